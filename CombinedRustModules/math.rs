@@ -23,6 +23,23 @@ pub mod math {
 
         // Currently using Default for Zero / AdditiveIdentity
 
+        /// Conversion of a count (like the length of a range), without the overhead of `TryFrom`.
+        pub trait FromUsize {
+            fn from_usize(x: usize) -> Self;
+        }
+        macro_rules! impl_from_usize_for_primitives {
+            ($($t:ty),*) => {
+                $(impl FromUsize for $t {
+                    fn from_usize(x: usize) -> Self {
+                        x as $t
+                    }
+                })*
+            };
+        }
+        impl_from_usize_for_primitives!(
+            u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+        );
+
         pub trait MultiplicativeInverse {
             type Output;
             fn mul_inv(&self) -> Self::Output;
@@ -109,8 +126,42 @@ pub mod math {
         impl_rounded_midpoint_trait_for_integers!(
             u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
         );
+
+        /// The smallest and the largest values, used as the identities of `min` and `max`.
+        pub trait Bounded {
+            fn min_value() -> Self;
+            fn max_value() -> Self;
+        }
+        macro_rules! impl_bounded_for_integers {
+            ($($t:ty),*) => {
+                $(impl Bounded for $t {
+                    fn min_value() -> Self {
+                        <$t>::MIN
+                    }
+                    fn max_value() -> Self {
+                        <$t>::MAX
+                    }
+                })*
+            };
+        }
+        impl_bounded_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        macro_rules! impl_bounded_for_floats {
+            ($($t:ty),*) => {
+                $(impl Bounded for $t {
+                    fn min_value() -> Self {
+                        <$t>::NEG_INFINITY
+                    }
+                    fn max_value() -> Self {
+                        <$t>::INFINITY
+                    }
+                })*
+            };
+        }
+        impl_bounded_for_floats!(f32, f64);
     }
 
+    /// `base^exp` by binary exponentiation. The base isn't squared after the highest bit of `exp`, so it overflows
+    /// only if the answer does.
     pub fn pow_with_identity<T, P>(mut base: T, mut exp: P, identity: T) -> T
     where
         T: Mul<Output = T> + Copy,
@@ -121,8 +172,11 @@ pub mod math {
             if (exp & P::from(1u8)) == P::from(1u8) {
                 res = res * base;
             }
-            base = base * base;
             exp >>= P::from(1u8);
+            // Not squaring after the last bit, as it might overflow even if the answer doesn't
+            if exp > P::default() {
+                base = base * base;
+            }
         }
         res
     }
@@ -0,0 +1,169 @@
+/// # 2D BIT / Fenwick Tree ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/FenwickTree/fenwick_2d.rs))
+///
+/// `FenwickTree2D` for point add, rectangle sum, and `RangeFenwickTree2D` for rectangle add, rectangle sum, both taking
+/// a pair of ranges like `query(r1..=r2, c1..=c2)`. `RangeFenwickTree2D` negates the updates, so it needs a signed `T`
+/// (or `ModInt`).
+///
+/// You'll need the modules [my_utils.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/CombinedRustModules/my_utils.rs)
+/// and [math.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/CombinedRustModules/math.rs) to use the same.
+pub mod fenwick_2d {
+    use std::ops::{AddAssign, Mul, Neg, RangeBounds, Sub};
+
+    /// Point add, rectangle sum.
+    pub struct FenwickTree2D<T: AddAssign + Default + Copy + Clone> {
+        pub n: usize,
+        pub m: usize,
+        tree: Vec<Vec<T>>,
+    }
+
+    impl<T: AddAssign + Default + Copy + Clone> FenwickTree2D<T> {
+        pub fn new(n: usize, m: usize) -> Self {
+            Self {
+                n,
+                m,
+                tree: vec![vec![T::default(); m + 1]; n + 1],
+            }
+        }
+
+        pub fn add(&mut self, i: usize, j: usize, x: T) {
+            let mut i = i as isize + 1;
+            while i <= self.n as isize {
+                let mut j = j as isize + 1;
+                while j <= self.m as isize {
+                    self.tree[i as usize][j as usize] += x;
+                    j += j & -j;
+                }
+                i += i & -i;
+            }
+        }
+
+        /// The sum of the rectangle `[0, i] x [0, j]`.
+        pub fn get(&self, i: usize, j: usize) -> T {
+            self.prefix(i + 1, j + 1)
+        }
+
+        /// The sum of the rectangle `[0, i) x [0, j)`.
+        fn prefix(&self, i: usize, j: usize) -> T {
+            let mut ans = T::default();
+            let mut i = i as isize;
+            while i > 0 {
+                let mut j = j as isize;
+                while j > 0 {
+                    ans += self.tree[i as usize][j as usize];
+                    j -= j & -j;
+                }
+                i -= i & -i;
+            }
+            ans
+        }
+    }
+
+    impl<T: AddAssign + Default + Copy + Clone + Sub<Output = T>> FenwickTree2D<T> {
+        /// The sum of the rectangle `rows x cols`.
+        pub fn query<R, C>(&self, rows: R, cols: C) -> T
+        where
+            R: RangeBounds<usize>,
+            C: RangeBounds<usize>,
+        {
+            let (r1, r2) = super::get_inclusive_usize_bounds(&rows, self.n);
+            let (c1, c2) = super::get_inclusive_usize_bounds(&cols, self.m);
+            let mut ans = self.prefix(r2 + 1, c2 + 1) - self.prefix(r1, c2 + 1);
+            ans += self.prefix(r1, c1);
+            ans - self.prefix(r2 + 1, c1)
+        }
+    }
+
+    /// Rectangle add, rectangle sum.
+    ///
+    /// Keeps four `FenwickTree2D` over the 2D difference array `d`, with `d[p][q]`, `d[p][q] * p`, `d[p][q] * q` and
+    /// `d[p][q] * p * q`.
+    pub struct RangeFenwickTree2D<T>
+    where
+        T: AddAssign + Default + Copy + Clone,
+    {
+        pub n: usize,
+        pub m: usize,
+        trees: [FenwickTree2D<T>; 4],
+
+        /// `index[i] = i`, as `T`.
+        index: Vec<T>,
+    }
+
+    impl<T> RangeFenwickTree2D<T>
+    where
+        T: AddAssign
+            + Default
+            + Copy
+            + Clone
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Neg<Output = T>
+            + super::MultiplicativeIdentity,
+    {
+        pub fn new(n: usize, m: usize) -> Self {
+            let mut index = vec![T::default(); n.max(m) + 1];
+            for i in 1..index.len() {
+                index[i] = index[i - 1];
+                index[i] += T::one();
+            }
+            Self {
+                n,
+                m,
+                trees: [(); 4].map(|_| FenwickTree2D::new(n, m)),
+                index,
+            }
+        }
+
+        fn add_difference(&mut self, p: usize, q: usize, x: T) {
+            if p >= self.n || q >= self.m {
+                return;
+            }
+            let (ip, iq) = (self.index[p], self.index[q]);
+            self.trees[0].add(p, q, x);
+            self.trees[1].add(p, q, x * ip);
+            self.trees[2].add(p, q, x * iq);
+            self.trees[3].add(p, q, x * ip * iq);
+        }
+
+        /// Adds `x` to every element of the rectangle `rows x cols`.
+        pub fn add<R, C>(&mut self, rows: R, cols: C, x: T)
+        where
+            R: RangeBounds<usize>,
+            C: RangeBounds<usize>,
+        {
+            let (r1, r2) = super::get_inclusive_usize_bounds(&rows, self.n);
+            let (c1, c2) = super::get_inclusive_usize_bounds(&cols, self.m);
+            if r1 > r2 || c1 > c2 {
+                return;
+            }
+            let neg = -x;
+            self.add_difference(r1, c1, x);
+            self.add_difference(r1, c2 + 1, neg);
+            self.add_difference(r2 + 1, c1, neg);
+            self.add_difference(r2 + 1, c2 + 1, x);
+        }
+
+        /// The sum of the rectangle `[0, i) x [0, j)`.
+        fn prefix(&self, i: usize, j: usize) -> T {
+            let (x, y) = (self.index[i], self.index[j]);
+            let [d, dp, dq, dpq] = [0, 1, 2, 3].map(|t| self.trees[t].prefix(i, j));
+            let mut ans = x * y * d - y * dp;
+            ans += dpq;
+            ans - x * dq
+        }
+
+        /// The sum of the rectangle `rows x cols`.
+        pub fn query<R, C>(&self, rows: R, cols: C) -> T
+        where
+            R: RangeBounds<usize>,
+            C: RangeBounds<usize>,
+        {
+            let (r1, r2) = super::get_inclusive_usize_bounds(&rows, self.n);
+            let (c1, c2) = super::get_inclusive_usize_bounds(&cols, self.m);
+            let mut ans = self.prefix(r2 + 1, c2 + 1) - self.prefix(r1, c2 + 1);
+            ans += self.prefix(r1, c1);
+            ans - self.prefix(r2 + 1, c1)
+        }
+    }
+}
+use fenwick_2d::*;
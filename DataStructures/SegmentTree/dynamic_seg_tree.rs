@@ -0,0 +1,541 @@
+/// # Dynamic Segment Tree ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/dynamic_seg_tree.rs))
+///
+/// For huge index ranges (like `[0, 10^18]` or `[-10^18, 10^18]`) without coordinate compression, the nodes are
+/// created only when they're required.
+///
+/// You'll need the module [seg_tree.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree.rs) to use the same.
+pub mod dynamic_seg_tree {
+    use super::{LazySegmentTreeOperations, SegmentTreeOperations};
+    use std::ops::{Bound, RangeBounds};
+
+    /// The integer types which can be used as indices.
+    pub trait DynamicSegmentTreeIndex: Copy {
+        fn to_i128(self) -> i128;
+
+        fn from_i128(x: i128) -> Self;
+    }
+    macro_rules! impl_dynamic_segment_tree_index {
+        ($($t:ty),*) => {
+            $(impl DynamicSegmentTreeIndex for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(x: i128) -> Self {
+                    x as $t
+                }
+            })*
+        };
+    }
+    impl_dynamic_segment_tree_index!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    /// The index range `[lo, hi]` covered by a tree, and the data of the nodes which aren't created yet.
+    ///
+    /// The leaves are numbered by their offset from `lo`, and their count is rounded up to a power of 2, so that a
+    /// missing node covering `2^h` leaves has the (precomputed) data `defaults[h]`.
+    struct Layout<I: DynamicSegmentTreeIndex, D> {
+        lo: I,
+        /// The offset of `hi`.
+        last: u64,
+        /// The offset of the last leaf, i.e. `2^height - 1`.
+        capacity_last: u64,
+        defaults: Vec<D>,
+    }
+
+    impl<I: DynamicSegmentTreeIndex, D: Clone> Layout<I, D> {
+        fn new<F: Fn(&D, &D) -> D>(lo: I, hi: I, value: D, merge: F) -> Self {
+            let len = hi.to_i128() - lo.to_i128() + 1;
+            assert!(
+                (1..=1 << 64).contains(&len),
+                "The range should be non-empty, with at most 2^64 indices"
+            );
+            let capacity = (len as u128).next_power_of_two();
+            let mut defaults = vec![value];
+            for h in 1..=capacity.trailing_zeros() as usize {
+                defaults.push(merge(&defaults[h - 1], &defaults[h - 1]));
+            }
+            Self {
+                lo,
+                last: (len - 1) as u64,
+                capacity_last: (capacity - 1) as u64,
+                defaults,
+            }
+        }
+
+        fn offset(&self, x: I) -> u64 {
+            let offset = x.to_i128() - self.lo.to_i128();
+            assert!(
+                offset >= 0 && offset <= self.last as i128,
+                "Index out of the range of the tree"
+            );
+            offset as u64
+        }
+
+        fn index(&self, offset: u64) -> I {
+            I::from_i128(self.lo.to_i128() + offset as i128)
+        }
+
+        /// The inclusive offsets of the range, `None` if it's empty.
+        fn bounds<R: RangeBounds<I>>(&self, range: &R) -> Option<(u64, u64)> {
+            let offset = |x: &I| x.to_i128() - self.lo.to_i128();
+            let l = match range.start_bound() {
+                Bound::Included(x) => offset(x),
+                Bound::Excluded(x) => offset(x) + 1,
+                Bound::Unbounded => 0,
+            };
+            let r = match range.end_bound() {
+                Bound::Included(x) => offset(x),
+                Bound::Excluded(x) => offset(x) - 1,
+                Bound::Unbounded => self.last as i128,
+            };
+            if l > r {
+                return None;
+            }
+            assert!(
+                l >= 0 && r <= self.last as i128,
+                "Range out of the range of the tree"
+            );
+            Some((l as u64, r as u64))
+        }
+
+        /// The data of a missing node covering `[l, r]`.
+        fn default_of(&self, l: u64, r: u64) -> D {
+            self.defaults[(u64::BITS - (r - l).leading_zeros()) as usize].clone()
+        }
+    }
+
+    /// The index of a missing node, i.e. all of its leaves are still equal to the initial value.
+    const MISSING: usize = usize::MAX;
+
+    struct Node<D> {
+        data: D,
+        children: [usize; 2],
+    }
+
+    /// Point-update Segment Tree over the indices `[lo, hi]`.
+    ///
+    /// Every update creates `O(log(hi - lo))` nodes, and the queries don't create any.
+    pub struct DynamicSegmentTree<Ops: SegmentTreeOperations, I: DynamicSegmentTreeIndex> {
+        layout: Layout<I, Ops::Data>,
+        /// The root is index `0`.
+        nodes: Vec<Node<Ops::Data>>,
+    }
+
+    impl<Ops: SegmentTreeOperations, I: DynamicSegmentTreeIndex> DynamicSegmentTree<Ops, I> {
+        /// Initialize the nodes with `Ops::data_identity()`
+        pub fn new(lo: I, hi: I) -> Self {
+            Self::with_value(lo, hi, Ops::data_identity())
+        }
+
+        /// Initialize all the elements with `value`
+        pub fn with_value(lo: I, hi: I, value: Ops::Data) -> Self {
+            let layout = Layout::new(lo, hi, value, Ops::merge);
+            let root = Node {
+                data: layout.default_of(0, layout.capacity_last),
+                children: [MISSING; 2],
+            };
+            Self {
+                layout,
+                nodes: vec![root],
+            }
+        }
+
+        /// The number of nodes created till now.
+        pub fn node_count(&self) -> usize {
+            self.nodes.len()
+        }
+
+        fn data(&self, v: usize, l: u64, r: u64) -> Ops::Data {
+            if v == MISSING {
+                self.layout.default_of(l, r)
+            } else {
+                self.nodes[v].data.clone()
+            }
+        }
+
+        /// The children of `v`, which are missing for a missing node as well.
+        fn children(&self, v: usize) -> [usize; 2] {
+            if v == MISSING {
+                [MISSING; 2]
+            } else {
+                self.nodes[v].children
+            }
+        }
+
+        /// Range query over `range`.
+        pub fn query<R>(&self, range: R) -> Ops::Data
+        where
+            R: RangeBounds<I>,
+        {
+            match self.layout.bounds(&range) {
+                Some((l, r)) => self._query(0, 0, self.layout.capacity_last, l, r),
+                None => Ops::data_identity(),
+            }
+        }
+
+        fn _query(&self, v: usize, l: u64, r: u64, ql: u64, qr: u64) -> Ops::Data {
+            if ql > r || qr < l {
+                return Ops::data_identity();
+            }
+            if ql <= l && r <= qr {
+                return self.data(v, l, r);
+            }
+
+            let m = l + (r - l) / 2;
+            let [left, right] = self.children(v);
+            Ops::merge(
+                &self._query(left, l, m, ql, qr),
+                &self._query(right, m + 1, r, ql, qr),
+            )
+        }
+
+        /// The value at index `pos`.
+        pub fn get(&self, pos: I) -> Ops::Data {
+            self.query(pos..=pos)
+        }
+
+        /// Overwrites the value at index `pos` by `x`.
+        pub fn set(&mut self, pos: I, x: Ops::Data) {
+            let pos = self.layout.offset(pos);
+            self._set(0, 0, self.layout.capacity_last, pos, x);
+        }
+
+        fn _set(&mut self, v: usize, l: u64, r: u64, pos: u64, x: Ops::Data) {
+            if l == r {
+                self.nodes[v].data = x;
+                return;
+            }
+
+            let m = l + (r - l) / 2;
+            let (child, cl, cr) = if pos <= m { (0, l, m) } else { (1, m + 1, r) };
+            if self.nodes[v].children[child] == MISSING {
+                self.nodes[v].children[child] = self.nodes.len();
+                self.nodes.push(Node {
+                    data: self.layout.default_of(cl, cr),
+                    children: [MISSING; 2],
+                });
+            }
+            self._set(self.nodes[v].children[child], cl, cr, pos, x);
+
+            let [left, right] = self.nodes[v].children;
+            self.nodes[v].data = Ops::merge(&self.data(left, l, m), &self.data(right, m + 1, r));
+        }
+
+        /// Point-update in the Segment Tree, same as `SegmentTree::update`.
+        pub fn update<F>(&mut self, pos: I, update_to: F)
+        where
+            F: Fn(Ops::Data) -> Ops::Data,
+        {
+            let x = update_to(self.get(pos));
+            self.set(pos, x);
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns an option containing the first index from the range that satisfies the condition, `None` if no
+        /// element satisfies the condition.
+        pub fn first_true<R, F>(&self, range: R, mut pred: F) -> Option<I>
+        where
+            R: RangeBounds<I>,
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            let (l, r) = self.layout.bounds(&range)?;
+            self._first_true(0, 0, self.layout.capacity_last, l, r, &mut pred)
+                .map(|offset| self.layout.index(offset))
+        }
+
+        fn _first_true<F>(
+            &self,
+            v: usize,
+            l: u64,
+            r: u64,
+            ql: u64,
+            qr: u64,
+            pred: &mut F,
+        ) -> Option<u64>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            if qr < l || ql > r {
+                return None;
+            }
+            if !pred(&self.data(v, l, r)) {
+                return None;
+            }
+            if l == r {
+                return Some(l);
+            }
+
+            let m = l + (r - l) / 2;
+            let [left, right] = self.children(v);
+            self._first_true(left, l, m, ql, qr, pred)
+                .or_else(|| self._first_true(right, m + 1, r, ql, qr, pred))
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns an option containing the last index from the range that satisfies the condition, `None` if no
+        /// element satisfies the condition.
+        pub fn last_true<R, F>(&self, range: R, mut pred: F) -> Option<I>
+        where
+            R: RangeBounds<I>,
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            let (l, r) = self.layout.bounds(&range)?;
+            self._last_true(0, 0, self.layout.capacity_last, l, r, &mut pred)
+                .map(|offset| self.layout.index(offset))
+        }
+
+        fn _last_true<F>(
+            &self,
+            v: usize,
+            l: u64,
+            r: u64,
+            ql: u64,
+            qr: u64,
+            pred: &mut F,
+        ) -> Option<u64>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            if qr < l || ql > r {
+                return None;
+            }
+            if !pred(&self.data(v, l, r)) {
+                return None;
+            }
+            if l == r {
+                return Some(l);
+            }
+
+            let m = l + (r - l) / 2;
+            let [left, right] = self.children(v);
+            self._last_true(right, m + 1, r, ql, qr, pred)
+                .or_else(|| self._last_true(left, l, m, ql, qr, pred))
+        }
+    }
+
+    struct LazyNode<D, L> {
+        data: D,
+        lazy: L,
+        children: [usize; 2],
+    }
+
+    /// Segment Tree with Lazy Propagation over the indices `[lo, hi]`.
+    ///
+    /// The children of a node are created when it's pushed, so every operation creates `O(log(hi - lo))` nodes.
+    ///
+    /// `Ops::apply` gets the range of the node as offsets from `lo`, so `r - l + 1` is still its length. That length
+    /// has to fit in a `usize`, so the range can have at most `2^63` indices (on a 64-bit target).
+    pub struct DynamicLazySegmentTree<Ops: LazySegmentTreeOperations, I: DynamicSegmentTreeIndex> {
+        layout: Layout<I, Ops::Data>,
+        /// The root is index `0`.
+        nodes: Vec<LazyNode<Ops::Data, Ops::Lazy>>,
+    }
+
+    impl<Ops: LazySegmentTreeOperations, I: DynamicSegmentTreeIndex> DynamicLazySegmentTree<Ops, I> {
+        /// Initialize the nodes with `Ops::data_identity()` and `Ops::lazy_identity()`
+        pub fn new(lo: I, hi: I) -> Self {
+            Self::with_value(lo, hi, Ops::data_identity())
+        }
+
+        /// Initialize all the elements with `value`
+        pub fn with_value(lo: I, hi: I, value: Ops::Data) -> Self {
+            let layout = Layout::new(lo, hi, value, Ops::merge);
+            assert!(
+                layout.capacity_last < usize::MAX as u64,
+                "The range is too long for the lengths of the nodes to fit in a usize"
+            );
+            let root = LazyNode {
+                data: layout.default_of(0, layout.capacity_last),
+                lazy: Ops::lazy_identity(),
+                children: [MISSING; 2],
+            };
+            Self {
+                layout,
+                nodes: vec![root],
+            }
+        }
+
+        /// The number of nodes created till now.
+        pub fn node_count(&self) -> usize {
+            self.nodes.len()
+        }
+
+        fn apply_at(&mut self, v: usize, l: u64, r: u64, update_to: &Ops::Lazy) {
+            let node = &mut self.nodes[v];
+            node.data = Ops::apply(&node.data, update_to, l as usize, r as usize);
+            node.lazy = Ops::compose(&node.lazy, update_to);
+        }
+
+        /// Creates the missing children, and pushes the lazy value to them.
+        fn push(&mut self, v: usize, l: u64, r: u64) {
+            let m = l + (r - l) / 2;
+            for (child, cl, cr) in [(0, l, m), (1, m + 1, r)] {
+                if self.nodes[v].children[child] == MISSING {
+                    self.nodes[v].children[child] = self.nodes.len();
+                    self.nodes.push(LazyNode {
+                        data: self.layout.default_of(cl, cr),
+                        lazy: Ops::lazy_identity(),
+                        children: [MISSING; 2],
+                    });
+                }
+            }
+
+            let update_to = std::mem::replace(&mut self.nodes[v].lazy, Ops::lazy_identity());
+            let [left, right] = self.nodes[v].children;
+            self.apply_at(left, l, m, &update_to);
+            self.apply_at(right, m + 1, r, &update_to);
+        }
+
+        /// Range query over `range`
+        pub fn query<R>(&mut self, range: R) -> Ops::Data
+        where
+            R: RangeBounds<I>,
+        {
+            match self.layout.bounds(&range) {
+                Some((l, r)) => self._query(0, 0, self.layout.capacity_last, l, r),
+                None => Ops::data_identity(),
+            }
+        }
+
+        fn _query(&mut self, v: usize, l: u64, r: u64, ql: u64, qr: u64) -> Ops::Data {
+            if ql > r || qr < l {
+                return Ops::data_identity();
+            }
+            if ql <= l && r <= qr {
+                return self.nodes[v].data.clone();
+            }
+
+            self.push(v, l, r);
+            let m = l + (r - l) / 2;
+            let [left, right] = self.nodes[v].children;
+            Ops::merge(
+                &self._query(left, l, m, ql, qr),
+                &self._query(right, m + 1, r, ql, qr),
+            )
+        }
+
+        /// Range update according to the lazy value `update_to`.
+        pub fn update<R>(&mut self, range: R, update_to: Ops::Lazy)
+        where
+            R: RangeBounds<I>,
+        {
+            if let Some((l, r)) = self.layout.bounds(&range) {
+                self._update(0, 0, self.layout.capacity_last, l, r, &update_to);
+            }
+        }
+
+        fn _update(&mut self, v: usize, l: u64, r: u64, ql: u64, qr: u64, update_to: &Ops::Lazy) {
+            if qr < l || ql > r {
+                return;
+            }
+            if ql <= l && r <= qr {
+                self.apply_at(v, l, r, update_to);
+                return;
+            }
+
+            self.push(v, l, r);
+            let m = l + (r - l) / 2;
+            let [left, right] = self.nodes[v].children;
+            self._update(left, l, m, ql, qr, update_to);
+            self._update(right, m + 1, r, ql, qr, update_to);
+            self.nodes[v].data = Ops::merge(&self.nodes[left].data, &self.nodes[right].data);
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns an option containing the first index from the range that satisfies the condition, `None` if no
+        /// element satisfies the condition.
+        pub fn first_true<R, F>(&mut self, range: R, mut pred: F) -> Option<I>
+        where
+            R: RangeBounds<I>,
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            let (l, r) = self.layout.bounds(&range)?;
+            self._first_true(0, 0, self.layout.capacity_last, l, r, &mut pred)
+                .map(|offset| self.layout.index(offset))
+        }
+
+        fn _first_true<F>(
+            &mut self,
+            v: usize,
+            l: u64,
+            r: u64,
+            ql: u64,
+            qr: u64,
+            pred: &mut F,
+        ) -> Option<u64>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            if qr < l || ql > r {
+                return None;
+            }
+            if !pred(&self.nodes[v].data) {
+                return None;
+            }
+            if l == r {
+                return Some(l);
+            }
+
+            self.push(v, l, r);
+            let m = l + (r - l) / 2;
+            let [left, right] = self.nodes[v].children;
+            let found = self._first_true(left, l, m, ql, qr, pred);
+            if found.is_some() {
+                found
+            } else {
+                self._first_true(right, m + 1, r, ql, qr, pred)
+            }
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns an option containing the last index from the range that satisfies the condition, `None` if no
+        /// element satisfies the condition.
+        pub fn last_true<R, F>(&mut self, range: R, mut pred: F) -> Option<I>
+        where
+            R: RangeBounds<I>,
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            let (l, r) = self.layout.bounds(&range)?;
+            self._last_true(0, 0, self.layout.capacity_last, l, r, &mut pred)
+                .map(|offset| self.layout.index(offset))
+        }
+
+        fn _last_true<F>(
+            &mut self,
+            v: usize,
+            l: u64,
+            r: u64,
+            ql: u64,
+            qr: u64,
+            pred: &mut F,
+        ) -> Option<u64>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            if qr < l || ql > r {
+                return None;
+            }
+            if !pred(&self.nodes[v].data) {
+                return None;
+            }
+            if l == r {
+                return Some(l);
+            }
+
+            self.push(v, l, r);
+            let m = l + (r - l) / 2;
+            let [left, right] = self.nodes[v].children;
+            let found = self._last_true(right, m + 1, r, ql, qr, pred);
+            if found.is_some() {
+                found
+            } else {
+                self._last_true(left, l, m, ql, qr, pred)
+            }
+        }
+    }
+}
+use dynamic_seg_tree::*;
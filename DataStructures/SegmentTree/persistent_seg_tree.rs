@@ -0,0 +1,200 @@
+/// # Persistent Segment Tree ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/persistent_seg_tree.rs))
+///
+/// Every update creates a new version (sharing the unchanged nodes with the old one), and all the versions can still
+/// be queried.
+///
+/// You'll need the modules [seg_tree.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree.rs)
+/// and [my_utils.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/CombinedRustModules/my_utils.rs) to use the same.
+pub mod persistent_seg_tree {
+    use super::SegmentTreeOperations;
+    use std::ops::RangeBounds;
+
+    /// Node `0` has the data `Ops::data_identity()` and itself as both the children, so it's shared by all the
+    /// subtrees which are still at their defaults.
+    const IDENTITY_NODE: u32 = 0;
+
+    pub struct PersistentSegmentTree<Ops: SegmentTreeOperations> {
+        /// The number of elements passed during construction.
+        pub n: usize,
+
+        /// The arena of the nodes, `data[v]` with the children `left[v]` and `right[v]`.
+        data: Vec<Ops::Data>,
+        left: Vec<u32>,
+        right: Vec<u32>,
+
+        /// The root of every version, version `0` being the initial tree.
+        roots: Vec<u32>,
+    }
+
+    impl<Ops: SegmentTreeOperations> PersistentSegmentTree<Ops> {
+        /// Initialize the nodes equal to `nodes`
+        pub fn with_nodes(nodes: &Vec<Ops::Data>) -> Self {
+            Self::with_func(nodes.len(), |i| nodes[i].clone())
+        }
+
+        /// Initialize the nodes with `Ops::data_identity()`
+        ///
+        /// No node is created, so the memory used is only `O(log n)` per update.
+        pub fn with_defaults(n: usize) -> Self {
+            Self {
+                n,
+                data: vec![Ops::data_identity()],
+                left: vec![IDENTITY_NODE],
+                right: vec![IDENTITY_NODE],
+                roots: vec![IDENTITY_NODE],
+            }
+        }
+
+        /// Initialize the nodes according to the function/lambda `f`, taking the index and returning the data node.
+        pub fn with_func<F>(n: usize, f: F) -> Self
+        where
+            F: Fn(usize) -> Ops::Data,
+        {
+            let mut seg_tree = Self::with_defaults(n);
+            if n > 0 {
+                seg_tree.roots[0] = seg_tree.build(0, n - 1, &f);
+            }
+            seg_tree
+        }
+
+        fn build<F>(&mut self, l: usize, r: usize, f: &F) -> u32
+        where
+            F: Fn(usize) -> Ops::Data,
+        {
+            if l == r {
+                return self.new_node(f(l), IDENTITY_NODE, IDENTITY_NODE);
+            }
+            let m = (l + r) >> 1;
+            let left = self.build(l, m, f);
+            let right = self.build(m + 1, r, f);
+            self.new_node(
+                Ops::merge(&self.data[left as usize], &self.data[right as usize]),
+                left,
+                right,
+            )
+        }
+
+        fn new_node(&mut self, data: Ops::Data, left: u32, right: u32) -> u32 {
+            self.data.push(data);
+            self.left.push(left);
+            self.right.push(right);
+            (self.data.len() - 1) as u32
+        }
+
+        /// The number of versions, i.e. the versions are `0..version_count()`.
+        pub fn version_count(&self) -> usize {
+            self.roots.len()
+        }
+
+        /// The number of nodes created till now.
+        pub fn node_count(&self) -> usize {
+            self.data.len()
+        }
+
+        /// Range query over `range` in the given version.
+        pub fn query<R>(&self, version: usize, range: R) -> Ops::Data
+        where
+            R: RangeBounds<usize>,
+        {
+            let root = self.roots[version];
+            // The bounds are resolved against `n`, which would underflow for an empty tree
+            if self.n == 0 {
+                return Ops::data_identity();
+            }
+            let (l, r) = super::get_inclusive_usize_bounds(&range, self.n);
+            if l > r {
+                return Ops::data_identity();
+            }
+            self._query(root, 0, self.n - 1, l, r)
+        }
+
+        fn _query(&self, v: u32, l: usize, r: usize, ql: usize, qr: usize) -> Ops::Data {
+            if ql > r || qr < l {
+                return Ops::data_identity();
+            }
+            if ql <= l && r <= qr {
+                return self.data[v as usize].clone();
+            }
+
+            let m = (l + r) >> 1;
+            Ops::merge(
+                &self._query(self.left[v as usize], l, m, ql, qr),
+                &self._query(self.right[v as usize], m + 1, r, ql, qr),
+            )
+        }
+
+        /// Point-update on the given version, same as `SegmentTree::update`.
+        ///
+        /// The given version is unchanged, and the number of the new version is returned.
+        pub fn update<F>(&mut self, version: usize, pos: usize, update_to: F) -> usize
+        where
+            F: Fn(Ops::Data) -> Ops::Data,
+        {
+            assert!(
+                pos < self.n,
+                "Index {} out of bounds for size {}",
+                pos,
+                self.n
+            );
+            let root = self._update(self.roots[version], 0, self.n - 1, pos, &update_to);
+            self.roots.push(root);
+            self.roots.len() - 1
+        }
+
+        fn _update<F>(&mut self, v: u32, l: usize, r: usize, pos: usize, update_to: &F) -> u32
+        where
+            F: Fn(Ops::Data) -> Ops::Data,
+        {
+            if l == r {
+                let data = update_to(self.data[v as usize].clone());
+                return self.new_node(data, IDENTITY_NODE, IDENTITY_NODE);
+            }
+
+            let m = (l + r) >> 1;
+            let (mut left, mut right) = (self.left[v as usize], self.right[v as usize]);
+            if pos <= m {
+                left = self._update(left, l, m, pos, update_to);
+            } else {
+                right = self._update(right, m + 1, r, pos, update_to);
+            }
+            self.new_node(
+                Ops::merge(&self.data[left as usize], &self.data[right as usize]),
+                left,
+                right,
+            )
+        }
+
+        /// Descends using the difference between the versions `old` and `new`, returning the index of the `k`-th
+        /// (0-based) element between them, `None` if there are not enough elements.
+        ///
+        /// `count(old, new)` is the number of elements in a node between the versions. For example, with the counts of
+        /// the (compressed) values in the tree, and a version after inserting every prefix of the array, the `k`-th
+        /// smallest value of `a[l..=r]` is `kth(l, r + 1, k, |old, new| new - old)`.
+        pub fn kth<F>(&self, old: usize, new: usize, mut k: usize, count: F) -> Option<usize>
+        where
+            F: Fn(&Ops::Data, &Ops::Data) -> usize,
+        {
+            let (mut u, mut v) = (self.roots[old] as usize, self.roots[new] as usize);
+            if self.n == 0 || k >= count(&self.data[u], &self.data[v]) {
+                return None;
+            }
+
+            let (mut l, mut r) = (0, self.n - 1);
+            while l < r {
+                let m = (l + r) >> 1;
+                let (left_u, left_v) = (self.left[u] as usize, self.left[v] as usize);
+                let left_count = count(&self.data[left_u], &self.data[left_v]);
+                if k < left_count {
+                    (u, v) = (left_u, left_v);
+                    r = m;
+                } else {
+                    k -= left_count;
+                    (u, v) = (self.right[u] as usize, self.right[v] as usize);
+                    l = m + 1;
+                }
+            }
+            Some(l)
+        }
+    }
+}
+use persistent_seg_tree::*;
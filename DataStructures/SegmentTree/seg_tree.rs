@@ -88,40 +88,26 @@ pub mod seg_tree {
 
         /// Query without computing/merging nodes, and allows to use the nodes in the `access_node` function.
         ///
-        /// Probably need a better name.
-        ///
-        /// TODO: Try to create an iterator over nodes accessing l..=r
+        /// Probably need a better name. Same as iterating over [Self::iter_nodes].
         pub fn query2<R, F>(&self, range: R, mut access_node: F)
         where
             R: RangeBounds<usize>,
             F: FnMut(&Ops::Data),
         {
-            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
-            self._query2(1, 0, self.capacity - 1, l, r, &mut access_node);
+            self.iter_nodes(range)
+                .for_each(|(_, _, data)| access_node(data));
         }
 
-        fn _query2<F>(
-            &self,
-            v: usize,
-            l: usize,
-            r: usize,
-            ql: usize,
-            qr: usize,
-            access_node: &mut F,
-        ) where
-            F: FnMut(&Ops::Data),
+        /// Iterates over the `O(log n)` nodes exactly covering `range`, from left to right (so it works for the
+        /// non-commutative operations as well).
+        ///
+        /// Every node is given with the range `[l, r]` it covers.
+        pub fn iter_nodes<R>(&self, range: R) -> NodeIter<'_, Ops::Data>
+        where
+            R: RangeBounds<usize>,
         {
-            if ql > r || qr < l {
-                return;
-            }
-            if ql <= l && r <= qr {
-                access_node(&self.nodes[v]);
-                return;
-            }
-
-            let m = (l + r) >> 1;
-            self._query2(v << 1, l, m, ql, qr, access_node);
-            self._query2(v << 1 | 1, m + 1, r, ql, qr, access_node);
+            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
+            NodeIter::new(&self.nodes, self.capacity, l, r)
         }
 
         /// Point-update in the Segment Tree. The function `updateTo` allows multiple types of updates.
@@ -129,12 +115,25 @@ pub mod seg_tree {
         /// If we want to update the value to `x`, `updateTo = |c| x`
         ///
         /// If we want to increase the current value by `x`, `updateTo = |c| c + x`
-        pub fn update<F>(&mut self, mut pos: usize, update_to: F)
+        pub fn update<F>(&mut self, pos: usize, update_to: F)
         where
             F: Fn(Ops::Data) -> Ops::Data,
         {
+            let x = update_to(self.get(pos));
+            self.set(pos, x);
+        }
+
+        /// The value at index `pos`.
+        pub fn get(&self, pos: usize) -> Ops::Data {
+            assert!(pos < self.n, "Index {} out of bounds for size {}", pos, self.n);
+            self.nodes[self.capacity + pos].clone()
+        }
+
+        /// Overwrites the value at index `pos` by `x`.
+        pub fn set(&mut self, mut pos: usize, x: Ops::Data) {
+            assert!(pos < self.n, "Index {} out of bounds for size {}", pos, self.n);
             pos += self.capacity;
-            self.nodes[pos] = update_to(self.nodes[pos].clone());
+            self.nodes[pos] = x;
             pos >>= 1;
             while pos >= 1 {
                 self.nodes[pos] = Ops::merge(&self.nodes[pos << 1], &self.nodes[pos << 1 | 1]);
@@ -142,6 +141,11 @@ pub mod seg_tree {
             }
         }
 
+        /// All the `n` values.
+        pub fn to_vec(&self) -> Vec<Ops::Data> {
+            self.nodes[self.capacity..self.capacity + self.n].to_vec()
+        }
+
         /// Segment Tree Walk
         ///
         /// Returns an option containing the first index from the range `[0, n - 1]` that satisfies the condition,
@@ -229,6 +233,260 @@ pub mod seg_tree {
                 self._last_true(v << 1, l, m, ql, qr, pred)
             }
         }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns the largest `r` such that `pred` is true for the merged value of `[l, r]`, `None` if it's false even
+        /// for `[l, l]`. Unlike `first_true`, `pred` gets the value accumulated from `l`, so it should be monotone, and
+        /// true for `Ops::data_identity()`.
+        pub fn max_right<F>(&self, l: usize, mut pred: F) -> Option<usize>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            check_walk_start::<Ops, F>(self.n, l, &mut pred);
+            let Some((mut v, mut acc)) = max_right_up(self, l, &mut pred) else {
+                return Some(self.n - 1);
+            };
+            while v < self.capacity {
+                v = max_right_down(self, v, &mut acc, &mut pred);
+            }
+            (v - self.capacity).checked_sub(1).filter(|&r| r >= l)
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns the smallest `l` such that `pred` is true for the merged value of `[l, r]`, `None` if it's false
+        /// even for `[r, r]`. `pred` gets the value accumulated till `r`, so it should be monotone, and true for
+        /// `Ops::data_identity()`.
+        pub fn min_left<F>(&self, r: usize, mut pred: F) -> Option<usize>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            check_walk_start::<Ops, F>(self.n, r, &mut pred);
+            let Some((mut v, mut acc)) = min_left_up(self, r, &mut pred) else {
+                return Some(0);
+            };
+            while v < self.capacity {
+                v = min_left_down(self, v, &mut acc, &mut pred);
+            }
+            Some(v + 1 - self.capacity).filter(|&l| l <= r)
+        }
+    }
+
+    /// A tree with its nodes in an array (the root at `1`, the leaves starting at `capacity`), for the bottom-up walks.
+    trait WalkNodes<Ops: SegmentTreeOperations> {
+        fn n(&self) -> usize;
+
+        fn capacity(&self) -> usize;
+
+        fn node(&self, v: usize) -> &Ops::Data;
+    }
+
+    /// The lazy trees push the pending updates before the walks read the nodes.
+    trait PushNodes<Ops: SegmentTreeOperations>: WalkNodes<Ops> {
+        /// Pushes the pending updates of all the ancestors of the leaf `pos`, from the root.
+        fn push_to_leaf(&mut self, pos: usize);
+
+        /// Pushes the pending update of the internal node `v` to its children.
+        fn push_to_children(&mut self, v: usize);
+    }
+
+    impl<Ops: SegmentTreeOperations> WalkNodes<Ops> for SegmentTree<Ops> {
+        fn n(&self) -> usize {
+            self.n
+        }
+
+        fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        fn node(&self, v: usize) -> &Ops::Data {
+            &self.nodes[v]
+        }
+    }
+
+    fn check_walk_start<Ops, F>(n: usize, pos: usize, pred: &mut F)
+    where
+        Ops: SegmentTreeOperations,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        assert!(pos < n, "Index {} out of bounds for size {}", pos, n);
+        debug_assert!(
+            pred(&Ops::data_identity()),
+            "pred should be true for the identity"
+        );
+    }
+
+    /// Goes up from the leaf `l` in `max_right`, returning the first node for which `pred` is false (with the value
+    /// accumulated before it), or `None` if it's true till the end.
+    fn max_right_up<Ops, T, F>(tree: &T, l: usize, pred: &mut F) -> Option<(usize, Ops::Data)>
+    where
+        Ops: SegmentTreeOperations,
+        T: WalkNodes<Ops>,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        let mut l = l + tree.capacity();
+        let mut acc = Ops::data_identity();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let merged = Ops::merge(&acc, tree.node(l));
+            if !pred(&merged) {
+                return Some((l, acc));
+            }
+            acc = merged;
+            l += 1;
+            if l.is_power_of_two() {
+                return None;
+            }
+        }
+    }
+
+    /// Goes from the node `v` in `max_right` to its child for which `pred` is false, merging the left child into `acc`
+    /// if it's not that one.
+    fn max_right_down<Ops, T, F>(tree: &T, v: usize, acc: &mut Ops::Data, pred: &mut F) -> usize
+    where
+        Ops: SegmentTreeOperations,
+        T: WalkNodes<Ops>,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        let v = v << 1;
+        let merged = Ops::merge(acc, tree.node(v));
+        if pred(&merged) {
+            *acc = merged;
+            v + 1
+        } else {
+            v
+        }
+    }
+
+    /// Goes up from the leaf `r` in `min_left`, returning the first node for which `pred` is false (with the value
+    /// accumulated after it), or `None` if it's true till the start.
+    fn min_left_up<Ops, T, F>(tree: &T, r: usize, pred: &mut F) -> Option<(usize, Ops::Data)>
+    where
+        Ops: SegmentTreeOperations,
+        T: WalkNodes<Ops>,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        let mut r = r + 1 + tree.capacity();
+        let mut acc = Ops::data_identity();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let merged = Ops::merge(tree.node(r), &acc);
+            if !pred(&merged) {
+                return Some((r, acc));
+            }
+            acc = merged;
+            if r.is_power_of_two() {
+                return None;
+            }
+        }
+    }
+
+    /// Goes from the node `v` in `min_left` to its child for which `pred` is false, merging the right child into `acc`
+    /// if it's not that one.
+    fn min_left_down<Ops, T, F>(tree: &T, v: usize, acc: &mut Ops::Data, pred: &mut F) -> usize
+    where
+        Ops: SegmentTreeOperations,
+        T: WalkNodes<Ops>,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        let v = v << 1 | 1;
+        let merged = Ops::merge(tree.node(v), acc);
+        if pred(&merged) {
+            *acc = merged;
+            v - 1
+        } else {
+            v
+        }
+    }
+
+    /// `max_right` of the lazy trees, pushing the nodes before reading them.
+    fn lazy_max_right<Ops, T, F>(tree: &mut T, l: usize, mut pred: F) -> Option<usize>
+    where
+        Ops: SegmentTreeOperations,
+        T: PushNodes<Ops>,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        let (n, capacity) = (tree.n(), tree.capacity());
+        check_walk_start::<Ops, F>(n, l, &mut pred);
+        tree.push_to_leaf(l);
+        let Some((mut v, mut acc)) = max_right_up(tree, l, &mut pred) else {
+            return Some(n - 1);
+        };
+        while v < capacity {
+            tree.push_to_children(v);
+            v = max_right_down(tree, v, &mut acc, &mut pred);
+        }
+        (v - capacity).checked_sub(1).filter(|&r| r >= l)
+    }
+
+    /// `min_left` of the lazy trees, pushing the nodes before reading them.
+    fn lazy_min_left<Ops, T, F>(tree: &mut T, r: usize, mut pred: F) -> Option<usize>
+    where
+        Ops: SegmentTreeOperations,
+        T: PushNodes<Ops>,
+        F: FnMut(&Ops::Data) -> bool,
+    {
+        let (n, capacity) = (tree.n(), tree.capacity());
+        check_walk_start::<Ops, F>(n, r, &mut pred);
+        tree.push_to_leaf(r);
+        let Some((mut v, mut acc)) = min_left_up(tree, r, &mut pred) else {
+            return Some(0);
+        };
+        while v < capacity {
+            tree.push_to_children(v);
+            v = min_left_down(tree, v, &mut acc, &mut pred);
+        }
+        Some(v + 1 - capacity).filter(|&l| l <= r)
+    }
+
+    /// The nodes exactly covering a range, returned by `iter_nodes`.
+    pub struct NodeIter<'a, T> {
+        nodes: &'a [T],
+        capacity: usize,
+        /// The first index not covered yet.
+        pos: usize,
+        r: usize,
+    }
+
+    impl<'a, T> NodeIter<'a, T> {
+        fn new(nodes: &'a [T], capacity: usize, l: usize, r: usize) -> Self {
+            Self {
+                nodes,
+                capacity,
+                pos: l,
+                r,
+            }
+        }
+    }
+
+    impl<'a, T> Iterator for NodeIter<'a, T> {
+        /// `(l, r, data)`
+        type Item = (usize, usize, &'a T);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.pos > self.r {
+                return None;
+            }
+            // The largest node starting at `pos` is limited by its alignment, and then by `r`
+            let mut size = if self.pos == 0 {
+                self.capacity
+            } else {
+                1 << self.pos.trailing_zeros()
+            };
+            while self.pos + size - 1 > self.r {
+                size >>= 1;
+            }
+
+            let l = self.pos;
+            self.pos += size;
+            Some((l, self.pos - 1, &self.nodes[(self.capacity + l) / size]))
+        }
     }
 
     impl<Ops: SegmentTreeOperations> Debug for SegmentTree<Ops>
@@ -254,6 +512,19 @@ pub mod seg_tree {
         /// I pass the range `l` and `r` as they are often used in the updates.
         fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data;
 
+        /// Same as `apply`, but returns `None` when the update can't be applied on the whole node at once (like in the
+        /// Segment Tree Beats). The update is then pushed down to the children, and the node is recomputed from them.
+        ///
+        /// It must succeed on the leaves. Only `LazySegmentTree` and `IterativeLazySegmentTree` use it.
+        fn try_apply(
+            data: &Self::Data,
+            update: &Self::Lazy,
+            l: usize,
+            r: usize,
+        ) -> Option<Self::Data> {
+            Some(Self::apply(data, update, l, r))
+        }
+
         /// Update the lazy operation.
         fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy;
     }
@@ -318,8 +589,16 @@ pub mod seg_tree {
         }
 
         fn apply_at(&mut self, v: usize, l: usize, r: usize, update_to: &Ops::Lazy) {
-            self.data_nodes[v] = Ops::apply(&self.data_nodes[v], update_to, l, r);
             self.lazy_nodes[v] = Ops::compose(&self.lazy_nodes[v], update_to);
+            match Ops::try_apply(&self.data_nodes[v], update_to, l, r) {
+                Some(data) => self.data_nodes[v] = data,
+                None => {
+                    assert!(l < r, "The update couldn't be applied on the leaf {}", l);
+                    self.push(v, l, r);
+                    self.data_nodes[v] =
+                        Ops::merge(&self.data_nodes[v << 1], &self.data_nodes[v << 1 | 1]);
+                }
+            }
         }
 
         fn push(&mut self, v: usize, l: usize, r: usize) {
@@ -330,13 +609,26 @@ pub mod seg_tree {
             self.lazy_nodes[v] = Ops::lazy_identity();
         }
 
+        /// The bounds of `range`, `None` if it's empty.
+        ///
+        /// The open ends are resolved against `n` (and not `capacity`), so that the updates don't reach the padding.
+        fn bounds<R: RangeBounds<usize>>(&self, range: &R) -> Option<(usize, usize)> {
+            if self.n == 0 {
+                return None;
+            }
+            let (l, r) = super::get_inclusive_usize_bounds(range, self.n);
+            (l <= r).then_some((l, r))
+        }
+
         /// Range query over `range`
         pub fn query<R>(&mut self, range: R) -> Ops::Data
         where
             R: RangeBounds<usize>,
         {
-            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
-            self._query(1, 0, self.capacity - 1, l, r)
+            match self.bounds(&range) {
+                Some((l, r)) => self._query(1, 0, self.capacity - 1, l, r),
+                None => Ops::data_identity(),
+            }
         }
 
         fn _query(&mut self, v: usize, l: usize, r: usize, ql: usize, qr: usize) -> Ops::Data {
@@ -357,41 +649,80 @@ pub mod seg_tree {
 
         /// Query without computing/merging nodes, and allows to use the nodes in the `access_node` function.
         ///
-        /// Probably need a better name.
-        ///
-        /// TODO: Try to create an iterator over nodes accessing l..=r
+        /// Probably need a better name. Same as iterating over [Self::iter_nodes].
         pub fn query2<R, F>(&mut self, range: R, mut access_node: F)
         where
             R: RangeBounds<usize>,
             F: FnMut(&Ops::Data),
         {
-            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
-            self._query2(1, 0, self.capacity - 1, l, r, &mut access_node);
+            self.iter_nodes(range)
+                .for_each(|(_, _, data)| access_node(data));
         }
 
-        fn _query2<F>(
-            &mut self,
-            v: usize,
-            l: usize,
-            r: usize,
-            ql: usize,
-            qr: usize,
-            access_node: &mut F,
-        ) where
-            F: FnMut(&Ops::Data),
+        /// Iterates over the `O(log n)` nodes exactly covering `range`, from left to right (so it works for the
+        /// non-commutative operations as well).
+        ///
+        /// Every node is given with the range `[l, r]` it covers. The pending updates above these nodes are pushed
+        /// first, so their data is up to date.
+        pub fn iter_nodes<R>(&mut self, range: R) -> NodeIter<'_, Ops::Data>
+        where
+            R: RangeBounds<usize>,
         {
-            if ql > r || qr < l {
-                return;
+            match self.bounds(&range) {
+                Some((l, r)) => {
+                    // Every ancestor of the covering nodes is an ancestor of either `l` or `r`
+                    self.push_path(l);
+                    self.push_path(r);
+                    NodeIter::new(&self.data_nodes, self.capacity, l, r)
+                }
+                None => NodeIter::new(&self.data_nodes, self.capacity, 1, 0),
             }
-            if ql <= l && r <= qr {
-                access_node(&self.data_nodes[v]);
-                return;
+        }
+
+        /// Pushes the lazy values of all the ancestors of the leaf `pos`, from the root.
+        fn push_path(&mut self, pos: usize) {
+            for h in (1..=self.capacity.trailing_zeros()).rev() {
+                let v = (self.capacity + pos) >> h;
+                let l = (v << h) - self.capacity;
+                self.push(v, l, l + (1 << h) - 1);
             }
+        }
 
-            self.push(v, l, r);
-            let m = (l + r) >> 1;
-            self._query2(v << 1, l, m, ql, qr, access_node);
-            self._query2(v << 1 | 1, m + 1, r, ql, qr, access_node);
+        /// The value at index `pos`, after the pending updates.
+        pub fn get(&mut self, pos: usize) -> Ops::Data {
+            assert!(pos < self.n, "Index {} out of bounds for size {}", pos, self.n);
+            self.push_path(pos);
+            self.data_nodes[self.capacity + pos].clone()
+        }
+
+        /// Overwrites the value at index `pos` by `x` (the pending updates above it are pushed first).
+        pub fn set(&mut self, pos: usize, x: Ops::Data) {
+            assert!(pos < self.n, "Index {} out of bounds for size {}", pos, self.n);
+            self.push_path(pos);
+            let mut v = self.capacity + pos;
+            self.data_nodes[v] = x;
+            v >>= 1;
+            while v >= 1 {
+                self.data_nodes[v] =
+                    Ops::merge(&self.data_nodes[v << 1], &self.data_nodes[v << 1 | 1]);
+                v >>= 1;
+            }
+        }
+
+        /// Pushes the lazy value of the node `v`, finding the range it covers.
+        fn push_node(&mut self, v: usize) {
+            let height = self.capacity.trailing_zeros() - (usize::BITS - 1 - v.leading_zeros());
+            let l = (v << height) - self.capacity;
+            self.push(v, l, l + (1 << height) - 1);
+        }
+
+        /// All the `n` values, after pushing all the pending updates.
+        pub fn to_vec(&mut self) -> Vec<Ops::Data> {
+            // A parent is always pushed before its children
+            for v in 1..self.capacity {
+                self.push_node(v);
+            }
+            self.data_nodes[self.capacity..self.capacity + self.n].to_vec()
         }
 
         /// Range update according to the lazy value `update_to`.
@@ -399,8 +730,9 @@ pub mod seg_tree {
         where
             R: RangeBounds<usize>,
         {
-            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
-            self._update(1, 0, self.capacity - 1, l, r, &update_to);
+            if let Some((l, r)) = self.bounds(&range) {
+                self._update(1, 0, self.capacity - 1, l, r, &update_to);
+            }
         }
 
         fn _update(
@@ -436,7 +768,7 @@ pub mod seg_tree {
             R: RangeBounds<usize>,
             F: FnMut(&Ops::Data) -> bool,
         {
-            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
+            let (l, r) = self.bounds(&range)?;
             self._first_true(1, 0, self.capacity - 1, l, r, &mut pred)
         }
 
@@ -481,7 +813,7 @@ pub mod seg_tree {
             R: RangeBounds<usize>,
             F: FnMut(&Ops::Data) -> bool,
         {
-            let (l, r) = super::get_inclusive_usize_bounds(&range, self.capacity);
+            let (l, r) = self.bounds(&range)?;
             self._last_true(1, 0, self.capacity - 1, l, r, &mut pred)
         }
 
@@ -516,6 +848,46 @@ pub mod seg_tree {
                 self._last_true(v << 1, l, m, ql, qr, pred)
             }
         }
+
+        /// Same as `SegmentTree::max_right`.
+        pub fn max_right<F>(&mut self, l: usize, pred: F) -> Option<usize>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            lazy_max_right(self, l, pred)
+        }
+
+        /// Same as `SegmentTree::min_left`.
+        pub fn min_left<F>(&mut self, r: usize, pred: F) -> Option<usize>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            lazy_min_left(self, r, pred)
+        }
+    }
+
+    impl<Ops: LazySegmentTreeOperations> WalkNodes<Ops> for LazySegmentTree<Ops> {
+        fn n(&self) -> usize {
+            self.n
+        }
+
+        fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        fn node(&self, v: usize) -> &Ops::Data {
+            &self.data_nodes[v]
+        }
+    }
+
+    impl<Ops: LazySegmentTreeOperations> PushNodes<Ops> for LazySegmentTree<Ops> {
+        fn push_to_leaf(&mut self, pos: usize) {
+            self.push_path(pos);
+        }
+
+        fn push_to_children(&mut self, v: usize) {
+            self.push_node(v);
+        }
     }
 
     impl<Ops: LazySegmentTreeOperations> Debug for LazySegmentTree<Ops>
@@ -535,5 +907,290 @@ pub mod seg_tree {
             Ok(())
         }
     }
+
+    /// Same as [LazySegmentTree], but non-recursive (similar to the `lazy_segtree` of the AtCoder Library).
+    ///
+    /// Only the nodes on the paths of the range ends are pushed, which makes it noticeably faster. The walks are
+    /// [Self::max_right] and [Self::min_left], instead of `first_true` and `last_true`.
+    pub struct IterativeLazySegmentTree<Ops: LazySegmentTreeOperations> {
+        /// The number of elements passed during construction.
+        pub n: usize,
+
+        /// The number of leaf nodes we're considering in the construction.
+        ///
+        /// Equal to `n`'s ceiling power of 2.
+        capacity: usize,
+
+        /// The height of the tree, i.e. `log2(capacity)`.
+        log: u32,
+
+        /// All the data nodes.
+        ///
+        /// Its length is twice of `capacity`, and the root is index `1`.
+        data_nodes: Vec<Ops::Data>,
+
+        /// The lazy nodes of the internal nodes.
+        ///
+        /// Its length is `capacity`, as the leaves don't need them.
+        lazy_nodes: Vec<Ops::Lazy>,
+    }
+
+    impl<Ops: LazySegmentTreeOperations> IterativeLazySegmentTree<Ops> {
+        /// Initialize the data nodes equal to `nodes`
+        pub fn with_nodes(nodes: Vec<Ops::Data>) -> Self {
+            Self::with_func(nodes.len(), |i| nodes[i].clone())
+        }
+
+        /// Initialize the nodes with `Ops::data_identity()` and `Ops::lazy_identity()`
+        pub fn with_defaults(n: usize) -> Self {
+            let capacity = n.next_power_of_two();
+            Self {
+                n,
+                capacity,
+                log: capacity.trailing_zeros(),
+                data_nodes: vec![Ops::data_identity(); capacity << 1],
+                lazy_nodes: vec![Ops::lazy_identity(); capacity],
+            }
+        }
+
+        /// Initialize the data nodes according to the function/lambda `f`, taking the index and returning the data node.
+        pub fn with_func<F>(n: usize, f: F) -> Self
+        where
+            F: Fn(usize) -> Ops::Data,
+        {
+            let mut seg_tree = Self::with_defaults(n);
+            for i in 0..n {
+                seg_tree.data_nodes[seg_tree.capacity + i] = f(i);
+            }
+            for v in (1..seg_tree.capacity).rev() {
+                seg_tree.pull(v);
+            }
+
+            seg_tree
+        }
+
+        /// The range `[l, r]` covered by the node `v`.
+        fn node_range(&self, v: usize) -> (usize, usize) {
+            let height = self.log - (usize::BITS - 1 - v.leading_zeros());
+            let l = (v << height) - self.capacity;
+            (l, l + (1 << height) - 1)
+        }
+
+        fn pull(&mut self, v: usize) {
+            self.data_nodes[v] = Ops::merge(&self.data_nodes[v << 1], &self.data_nodes[v << 1 | 1]);
+        }
+
+        fn apply_at(&mut self, v: usize, update_to: &Ops::Lazy) {
+            let (l, r) = self.node_range(v);
+            if v < self.capacity {
+                self.lazy_nodes[v] = Ops::compose(&self.lazy_nodes[v], update_to);
+            }
+            match Ops::try_apply(&self.data_nodes[v], update_to, l, r) {
+                Some(data) => self.data_nodes[v] = data,
+                None => {
+                    assert!(
+                        v < self.capacity,
+                        "The update couldn't be applied on the leaf {}",
+                        l
+                    );
+                    self.push(v);
+                    self.pull(v);
+                }
+            }
+        }
+
+        fn push(&mut self, v: usize) {
+            let update_to = std::mem::replace(&mut self.lazy_nodes[v], Ops::lazy_identity());
+            self.apply_at(v << 1, &update_to);
+            self.apply_at(v << 1 | 1, &update_to);
+        }
+
+        /// Pushes the ancestors of the nodes covering `[l, r)` (`l` and `r` are the leaf indices here), from the top.
+        fn push_bounds(&mut self, l: usize, r: usize) {
+            for i in (1..=self.log).rev() {
+                if ((l >> i) << i) != l {
+                    self.push(l >> i);
+                }
+                if ((r >> i) << i) != r {
+                    self.push((r - 1) >> i);
+                }
+            }
+        }
+
+        /// The value at index `pos`, after the pending updates.
+        pub fn get(&mut self, pos: usize) -> Ops::Data {
+            assert!(pos < self.n, "Index {} out of bounds for size {}", pos, self.n);
+            let v = self.capacity + pos;
+            for i in (1..=self.log).rev() {
+                self.push(v >> i);
+            }
+            self.data_nodes[v].clone()
+        }
+
+        /// Overwrites the value at index `pos` by `x` (the pending updates above it are pushed first).
+        pub fn set(&mut self, pos: usize, x: Ops::Data) {
+            assert!(pos < self.n, "Index {} out of bounds for size {}", pos, self.n);
+            let v = self.capacity + pos;
+            for i in (1..=self.log).rev() {
+                self.push(v >> i);
+            }
+            self.data_nodes[v] = x;
+            for i in 1..=self.log {
+                self.pull(v >> i);
+            }
+        }
+
+        /// All the `n` values, after pushing all the pending updates.
+        pub fn to_vec(&mut self) -> Vec<Ops::Data> {
+            // A parent is always pushed before its children
+            for v in 1..self.capacity {
+                self.push(v);
+            }
+            self.data_nodes[self.capacity..self.capacity + self.n].to_vec()
+        }
+
+        /// Range query over `range`
+        pub fn query<R>(&mut self, range: R) -> Ops::Data
+        where
+            R: RangeBounds<usize>,
+        {
+            // The bounds are resolved against `n`, which would underflow for an empty tree
+            if self.n == 0 {
+                return Ops::data_identity();
+            }
+            let (l, r) = super::get_inclusive_usize_bounds(&range, self.n);
+            if l > r {
+                return Ops::data_identity();
+            }
+            let (mut l, mut r) = (l + self.capacity, r + 1 + self.capacity);
+            self.push_bounds(l, r);
+
+            let mut left = Ops::data_identity();
+            let mut right = Ops::data_identity();
+            while l < r {
+                if l & 1 == 1 {
+                    left = Ops::merge(&left, &self.data_nodes[l]);
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    right = Ops::merge(&self.data_nodes[r], &right);
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+            Ops::merge(&left, &right)
+        }
+
+        /// Range update according to the lazy value `update_to`.
+        pub fn update<R>(&mut self, range: R, update_to: Ops::Lazy)
+        where
+            R: RangeBounds<usize>,
+        {
+            if self.n == 0 {
+                return;
+            }
+            let (l, r) = super::get_inclusive_usize_bounds(&range, self.n);
+            if l > r {
+                return;
+            }
+            let (l, r) = (l + self.capacity, r + 1 + self.capacity);
+            self.push_bounds(l, r);
+
+            let (mut l2, mut r2) = (l, r);
+            while l2 < r2 {
+                if l2 & 1 == 1 {
+                    self.apply_at(l2, &update_to);
+                    l2 += 1;
+                }
+                if r2 & 1 == 1 {
+                    r2 -= 1;
+                    self.apply_at(r2, &update_to);
+                }
+                l2 >>= 1;
+                r2 >>= 1;
+            }
+
+            for i in 1..=self.log {
+                if ((l >> i) << i) != l {
+                    self.pull(l >> i);
+                }
+                if ((r >> i) << i) != r {
+                    self.pull((r - 1) >> i);
+                }
+            }
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns the largest `r` such that `pred` is true for the merged value of `[l, r]`, `None` if it's false even
+        /// for `[l, l]`. `pred` should be monotone, and true for `Ops::data_identity()`.
+        pub fn max_right<F>(&mut self, l: usize, pred: F) -> Option<usize>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            lazy_max_right(self, l, pred)
+        }
+
+        /// Segment Tree Walk
+        ///
+        /// Returns the smallest `l` such that `pred` is true for the merged value of `[l, r]`, `None` if it's false
+        /// even for `[r, r]`. `pred` should be monotone, and true for `Ops::data_identity()`.
+        pub fn min_left<F>(&mut self, r: usize, pred: F) -> Option<usize>
+        where
+            F: FnMut(&Ops::Data) -> bool,
+        {
+            lazy_min_left(self, r, pred)
+        }
+    }
+
+    impl<Ops: LazySegmentTreeOperations> WalkNodes<Ops> for IterativeLazySegmentTree<Ops> {
+        fn n(&self) -> usize {
+            self.n
+        }
+
+        fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        fn node(&self, v: usize) -> &Ops::Data {
+            &self.data_nodes[v]
+        }
+    }
+
+    impl<Ops: LazySegmentTreeOperations> PushNodes<Ops> for IterativeLazySegmentTree<Ops> {
+        fn push_to_leaf(&mut self, pos: usize) {
+            let v = self.capacity + pos;
+            for i in (1..=self.log).rev() {
+                self.push(v >> i);
+            }
+        }
+
+        fn push_to_children(&mut self, v: usize) {
+            self.push(v);
+        }
+    }
+
+    impl<Ops: LazySegmentTreeOperations> Debug for IterativeLazySegmentTree<Ops>
+    where
+        Ops::Data: Debug,
+        Ops::Lazy: Debug,
+    {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            for i in 1..self.capacity + self.n {
+                writeln!(f)?;
+                if i < self.capacity {
+                    write!(
+                        f,
+                        "{}: Data = {:?}, Lazy = {:?}",
+                        i, self.data_nodes[i], self.lazy_nodes[i]
+                    )?;
+                } else {
+                    write!(f, "{}: Data = {:?}", i, self.data_nodes[i])?;
+                }
+            }
+            Ok(())
+        }
+    }
 }
 use seg_tree::*;
@@ -0,0 +1,179 @@
+/// # 2D Segment Tree ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree_2d.rs))
+///
+/// Point update, rectangle query, in `O(log n * log m)`. It's a segment tree (over the rows) of segment trees (over the
+/// columns), so it takes `(2 * n') * (2 * m')` nodes, `n'` and `m'` being the ceiling powers of 2 of `n` and `m` (up to
+/// `16 * n * m`).
+///
+/// The rectangle is merged from blocks in no particular order, so the operation should be commutative as well.
+///
+/// You'll need the modules [seg_tree.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree.rs)
+/// and [my_utils.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/CombinedRustModules/my_utils.rs) to use the same.
+pub mod seg_tree_2d {
+    use super::SegmentTreeOperations;
+    use std::ops::RangeBounds;
+
+    pub struct SegmentTree2D<Ops: SegmentTreeOperations> {
+        /// The number of rows passed during construction.
+        pub n: usize,
+
+        /// The number of columns passed during construction.
+        pub m: usize,
+
+        /// `n`'s and `m`'s ceiling powers of 2.
+        row_capacity: usize,
+        col_capacity: usize,
+
+        /// The node `(r, c)` (the column node `c` of the row node `r`) is at `r * 2 * col_capacity + c`.
+        nodes: Vec<Ops::Data>,
+    }
+
+    impl<Ops: SegmentTreeOperations> SegmentTree2D<Ops> {
+        /// Initialize the nodes equal to `nodes`
+        pub fn with_nodes(nodes: &Vec<Vec<Ops::Data>>) -> Self {
+            let m = nodes.first().map_or(0, |row| row.len());
+            Self::with_func(nodes.len(), m, |i, j| nodes[i][j].clone())
+        }
+
+        /// Initialize the nodes with `Ops::data_identity()`
+        pub fn with_defaults(n: usize, m: usize) -> Self {
+            let row_capacity = n.next_power_of_two();
+            let col_capacity = m.next_power_of_two();
+            Self {
+                n,
+                m,
+                row_capacity,
+                col_capacity,
+                nodes: vec![Ops::data_identity(); (row_capacity << 1) * (col_capacity << 1)],
+            }
+        }
+
+        /// Initialize the nodes according to the function/lambda `f`, taking the cell `(i, j)` and returning the data
+        /// node.
+        pub fn with_func<F>(n: usize, m: usize, f: F) -> Self
+        where
+            F: Fn(usize, usize) -> Ops::Data,
+        {
+            let mut seg_tree = Self::with_defaults(n, m);
+            let (rc, cc) = (seg_tree.row_capacity, seg_tree.col_capacity);
+            for i in 0..n {
+                for j in 0..m {
+                    let v = seg_tree.id(rc + i, cc + j);
+                    seg_tree.nodes[v] = f(i, j);
+                }
+                for c in (1..cc).rev() {
+                    seg_tree.pull_col(rc + i, c);
+                }
+            }
+            for r in (1..rc).rev() {
+                for c in 1..cc << 1 {
+                    seg_tree.pull_row(r, c);
+                }
+            }
+
+            seg_tree
+        }
+
+        fn id(&self, r: usize, c: usize) -> usize {
+            r * (self.col_capacity << 1) + c
+        }
+
+        /// Recomputes the node `(r, c)` from its column children.
+        fn pull_col(&mut self, r: usize, c: usize) {
+            let (left, right) = (self.id(r, c << 1), self.id(r, c << 1 | 1));
+            let v = self.id(r, c);
+            self.nodes[v] = Ops::merge(&self.nodes[left], &self.nodes[right]);
+        }
+
+        /// Recomputes the node `(r, c)` from its row children.
+        fn pull_row(&mut self, r: usize, c: usize) {
+            let (top, bottom) = (self.id(r << 1, c), self.id(r << 1 | 1, c));
+            let v = self.id(r, c);
+            self.nodes[v] = Ops::merge(&self.nodes[top], &self.nodes[bottom]);
+        }
+
+        /// Query over the columns `[l, r]` of the row node `row`.
+        fn query_row(&self, row: usize, mut l: usize, mut r: usize) -> Ops::Data {
+            l += self.col_capacity;
+            r += self.col_capacity;
+            let mut left = Ops::data_identity();
+            let mut right = Ops::data_identity();
+            while l <= r {
+                if l & 1 == 1 {
+                    left = Ops::merge(&left, &self.nodes[self.id(row, l)]);
+                    l += 1;
+                }
+                if r & 1 == 0 {
+                    right = Ops::merge(&self.nodes[self.id(row, r)], &right);
+                    r -= 1;
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+            Ops::merge(&left, &right)
+        }
+
+        /// Rectangle query over `rows x cols`.
+        pub fn query<R, C>(&self, rows: R, cols: C) -> Ops::Data
+        where
+            R: RangeBounds<usize>,
+            C: RangeBounds<usize>,
+        {
+            let (mut l, mut r) = super::get_inclusive_usize_bounds(&rows, self.row_capacity);
+            let (cl, cr) = super::get_inclusive_usize_bounds(&cols, self.col_capacity);
+            l += self.row_capacity;
+            r += self.row_capacity;
+            let mut top = Ops::data_identity();
+            let mut bottom = Ops::data_identity();
+            while l <= r {
+                if l & 1 == 1 {
+                    top = Ops::merge(&top, &self.query_row(l, cl, cr));
+                    l += 1;
+                }
+                if r & 1 == 0 {
+                    bottom = Ops::merge(&self.query_row(r, cl, cr), &bottom);
+                    r -= 1;
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+            Ops::merge(&top, &bottom)
+        }
+
+        /// Point-update of the cell `(i, j)`, same as `SegmentTree::update`.
+        pub fn update<F>(&mut self, i: usize, j: usize, update_to: F)
+        where
+            F: Fn(Ops::Data) -> Ops::Data,
+        {
+            let x = update_to(self.get(i, j));
+            self.set(i, j, x);
+        }
+
+        /// The value of the cell `(i, j)`.
+        pub fn get(&self, i: usize, j: usize) -> Ops::Data {
+            self.nodes[self.id(self.row_capacity + i, self.col_capacity + j)].clone()
+        }
+
+        /// Overwrites the value of the cell `(i, j)` by `x`.
+        pub fn set(&mut self, i: usize, j: usize, x: Ops::Data) {
+            let (row, col) = (self.row_capacity + i, self.col_capacity + j);
+            let v = self.id(row, col);
+            self.nodes[v] = x;
+            let mut c = col >> 1;
+            while c >= 1 {
+                self.pull_col(row, c);
+                c >>= 1;
+            }
+
+            let mut r = row >> 1;
+            while r >= 1 {
+                let mut c = col;
+                while c >= 1 {
+                    self.pull_row(r, c);
+                    c >>= 1;
+                }
+                r >>= 1;
+            }
+        }
+    }
+}
+use seg_tree_2d::*;
@@ -0,0 +1,242 @@
+/// # Segment Tree Beats ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree_beats.rs))
+///
+/// Range chmin, chmax and add, with range sum, max and min queries (Ji Driver Segment Tree), in amortized
+/// `O(log^2 n)`. You can read more about it [here](https://codeforces.com/blog/entry/57319).
+///
+/// It's just the operations, to be used with `LazySegmentTree` (or `IterativeLazySegmentTree`):
+///
+/// ```ignore
+/// let mut seg_tree = LazySegmentTree::<ChminChmaxAddSum>::with_func(n, |i| BeatsNode::new(a[i]));
+/// seg_tree.update(l..=r, BeatsUpdate::chmin(x));
+/// let sum = seg_tree.query(l..=r).sum;
+/// ```
+///
+/// `i64::MIN` and `i64::MAX` mean "nothing" (like no `second_max`, or no `chmax` in an update), so the values and the
+/// arguments of `chmin`/`chmax` have to be strictly between them (and the sums should fit in `i64`).
+///
+/// You'll need the module [seg_tree.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree.rs)
+/// to use the same.
+pub mod seg_tree_beats {
+    use super::{LazySegmentTreeOperations, SegmentTreeOperations};
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BeatsNode {
+        pub sum: i64,
+        pub max: i64,
+        /// The largest value strictly smaller than `max`, `i64::MIN` if there's none.
+        pub second_max: i64,
+        pub max_count: usize,
+        pub min: i64,
+        /// The smallest value strictly larger than `min`, `i64::MAX` if there's none.
+        pub second_min: i64,
+        pub min_count: usize,
+        pub len: usize,
+    }
+
+    impl BeatsNode {
+        /// The leaf with the value `x`.
+        pub fn new(x: i64) -> Self {
+            check_value(x);
+            Self {
+                sum: x,
+                max: x,
+                second_max: i64::MIN,
+                max_count: 1,
+                min: x,
+                second_min: i64::MAX,
+                min_count: 1,
+                len: 1,
+            }
+        }
+
+        /// Replaces every `max` by `x`, where `second_max < x < max`.
+        fn lower_max(&mut self, x: i64) {
+            self.sum -= (self.max - x) * self.max_count as i64;
+            if self.min == self.max {
+                self.min = x;
+            } else if self.second_min == self.max {
+                self.second_min = x;
+            }
+            self.max = x;
+        }
+
+        /// Replaces every `min` by `x`, where `min < x < second_min`.
+        fn raise_min(&mut self, x: i64) {
+            self.sum += (x - self.min) * self.min_count as i64;
+            if self.max == self.min {
+                self.max = x;
+            } else if self.second_max == self.min {
+                self.second_max = x;
+            }
+            self.min = x;
+        }
+    }
+
+    /// The update `x -> min(max(x + add, lower), upper)`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BeatsUpdate {
+        pub add: i64,
+        pub lower: i64,
+        pub upper: i64,
+    }
+
+    impl BeatsUpdate {
+        pub fn add(x: i64) -> Self {
+            Self {
+                add: x,
+                ..Self::identity()
+            }
+        }
+
+        /// `a[i] = min(a[i], x)`
+        pub fn chmin(x: i64) -> Self {
+            check_value(x);
+            Self {
+                upper: x,
+                ..Self::identity()
+            }
+        }
+
+        /// `a[i] = max(a[i], x)`
+        pub fn chmax(x: i64) -> Self {
+            check_value(x);
+            Self {
+                lower: x,
+                ..Self::identity()
+            }
+        }
+
+        fn identity() -> Self {
+            Self {
+                add: 0,
+                lower: i64::MIN,
+                upper: i64::MAX,
+            }
+        }
+    }
+
+    fn check_value(x: i64) {
+        assert!(
+            x != i64::MIN && x != i64::MAX,
+            "The values should be strictly between i64::MIN and i64::MAX"
+        );
+    }
+
+    /// Adds `add` to a value, keeping `i64::MIN` and `i64::MAX` (meaning "nothing") as they are.
+    fn shift(x: i64, add: i64) -> i64 {
+        if x == i64::MIN || x == i64::MAX {
+            x
+        } else {
+            x + add
+        }
+    }
+
+    pub struct ChminChmaxAddSum;
+
+    impl SegmentTreeOperations for ChminChmaxAddSum {
+        type Data = BeatsNode;
+
+        fn data_identity() -> Self::Data {
+            BeatsNode {
+                sum: 0,
+                max: i64::MIN,
+                second_max: i64::MIN,
+                max_count: 0,
+                min: i64::MAX,
+                second_min: i64::MAX,
+                min_count: 0,
+                len: 0,
+            }
+        }
+
+        fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+            let (max, second_max, max_count) = if a.max == b.max {
+                (
+                    a.max,
+                    a.second_max.max(b.second_max),
+                    a.max_count + b.max_count,
+                )
+            } else if a.max > b.max {
+                (a.max, a.second_max.max(b.max), a.max_count)
+            } else {
+                (b.max, a.max.max(b.second_max), b.max_count)
+            };
+            let (min, second_min, min_count) = if a.min == b.min {
+                (
+                    a.min,
+                    a.second_min.min(b.second_min),
+                    a.min_count + b.min_count,
+                )
+            } else if a.min < b.min {
+                (a.min, a.second_min.min(b.min), a.min_count)
+            } else {
+                (b.min, a.min.min(b.second_min), b.min_count)
+            };
+            BeatsNode {
+                sum: a.sum + b.sum,
+                max,
+                second_max,
+                max_count,
+                min,
+                second_min,
+                min_count,
+                len: a.len + b.len,
+            }
+        }
+    }
+
+    impl LazySegmentTreeOperations for ChminChmaxAddSum {
+        type Lazy = BeatsUpdate;
+
+        fn lazy_identity() -> Self::Lazy {
+            BeatsUpdate::identity()
+        }
+
+        fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data {
+            Self::try_apply(data, update, l, r)
+                .expect("The update should be pushed to the children")
+        }
+
+        fn try_apply(
+            data: &Self::Data,
+            update: &Self::Lazy,
+            _: usize,
+            _: usize,
+        ) -> Option<Self::Data> {
+            if data.len == 0 {
+                return Some(data.clone());
+            }
+
+            let mut data = data.clone();
+            data.sum += update.add * data.len as i64;
+            data.max += update.add;
+            data.min += update.add;
+            data.second_max = shift(data.second_max, update.add);
+            data.second_min = shift(data.second_min, update.add);
+
+            if update.lower > data.min {
+                if update.lower >= data.second_min {
+                    return None;
+                }
+                data.raise_min(update.lower);
+            }
+            if update.upper < data.max {
+                if update.upper <= data.second_max {
+                    return None;
+                }
+                data.lower_max(update.upper);
+            }
+            Some(data)
+        }
+
+        fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+            let clamp = |x: i64| x.max(next.lower).min(next.upper);
+            BeatsUpdate {
+                add: prev.add + next.add,
+                lower: clamp(shift(prev.lower, next.add)),
+                upper: clamp(shift(prev.upper, next.add)),
+            }
+        }
+    }
+}
+use seg_tree_beats::*;
@@ -0,0 +1,337 @@
+/// # Segment Tree Operations ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree_ops.rs))
+///
+/// Ready-made `SegmentTreeOperations` and `LazySegmentTreeOperations`, generic over the number type (including
+/// `ModInt` wherever only `+` and `*` are needed):
+///
+/// ```ignore
+/// let mut seg_tree = SegmentTree::<Min<i64>>::with_nodes(&a);
+/// let mut lazy_seg_tree = LazySegmentTree::<AffineSum<ModInt<Mod1e9_7>>>::with_func(n, |i| a[i]);
+/// lazy_seg_tree.update(l..=r, (b, c)); // a[i] = b * a[i] + c
+/// ```
+///
+/// The lazy operations on sums take the length of a node from the range `[l, r]` passed to `apply`, so any leaves
+/// (and `with_defaults`) work.
+///
+/// For `min` and `max`, the identity (`T::max_value()` and `T::min_value()`, from `Bounded`) shouldn't be a value of
+/// the array, as the lazy updates don't change the nodes with the identity (the padding after the `n` elements).
+///
+/// You'll need the modules [seg_tree.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/SegmentTree/seg_tree.rs)
+/// and [math.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/CombinedRustModules/math.rs) to use the same.
+pub mod seg_tree_ops {
+    use super::{
+        gcd, Bounded, FromUsize, LazySegmentTreeOperations, MultiplicativeIdentity,
+        SegmentTreeOperations,
+    };
+    use std::marker::PhantomData;
+    use std::ops::{Add, BitXor, Mul, Rem};
+
+    /// Range sum.
+    pub struct Sum<T>(PhantomData<T>);
+    /// Range min.
+    pub struct Min<T>(PhantomData<T>);
+    /// Range max.
+    pub struct Max<T>(PhantomData<T>);
+    /// Range gcd.
+    pub struct Gcd<T>(PhantomData<T>);
+    /// Range xor.
+    pub struct Xor<T>(PhantomData<T>);
+
+    /// Implements `SegmentTreeOperations` with the data `T`.
+    macro_rules! impl_monoid {
+        ($name:ident, [$($bounds:tt)*], $identity:expr, |$a:ident, $b:ident| $merge:expr) => {
+            impl<T: $($bounds)*> SegmentTreeOperations for $name<T> {
+                type Data = T;
+
+                fn data_identity() -> Self::Data {
+                    $identity
+                }
+
+                fn merge($a: &Self::Data, $b: &Self::Data) -> Self::Data {
+                    $merge
+                }
+            }
+        };
+    }
+
+    impl_monoid!(
+        Sum,
+        [Add<Output = T> + Default + Copy],
+        T::default(),
+        |a, b| *a + *b
+    );
+    impl_monoid!(
+        Min,
+        [PartialOrd + Bounded + Copy],
+        T::max_value(),
+        |a, b| if b < a { *b } else { *a }
+    );
+    impl_monoid!(
+        Max,
+        [PartialOrd + Bounded + Copy],
+        T::min_value(),
+        |a, b| if b > a { *b } else { *a }
+    );
+    impl_monoid!(
+        Gcd,
+        [Default + Rem<Output = T> + PartialEq + Copy],
+        T::default(),
+        |a, b| gcd(*a, *b)
+    );
+    impl_monoid!(
+        Xor,
+        [BitXor<Output = T> + Default + Copy],
+        T::default(),
+        |a, b| *a ^ *b
+    );
+
+    /// The minimum with the number of times it occurs, the leaves being `(x, 1)`.
+    pub struct MinCount<T>(PhantomData<T>);
+
+    impl<T: PartialOrd + Bounded + Copy> SegmentTreeOperations for MinCount<T> {
+        type Data = (T, usize);
+
+        fn data_identity() -> Self::Data {
+            (T::max_value(), 0)
+        }
+
+        fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+            if a.0 < b.0 {
+                *a
+            } else if b.0 < a.0 {
+                *b
+            } else {
+                (a.0, a.1 + b.1)
+            }
+        }
+    }
+
+    /// The node of [MaxSubarraySum].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct SubarrayNode<T> {
+        pub sum: T,
+        /// The maximum sum of a non-empty prefix.
+        pub prefix: T,
+        /// The maximum sum of a non-empty suffix.
+        pub suffix: T,
+        /// The maximum sum of a non-empty subarray.
+        pub best: T,
+        /// `false` only for the identity.
+        non_empty: bool,
+    }
+
+    impl<T: Copy> SubarrayNode<T> {
+        pub fn new(x: T) -> Self {
+            Self {
+                sum: x,
+                prefix: x,
+                suffix: x,
+                best: x,
+                non_empty: true,
+            }
+        }
+    }
+
+    /// The maximum sum of a (non-empty) subarray, the leaves being `SubarrayNode::new(x)`.
+    pub struct MaxSubarraySum<T>(PhantomData<T>);
+
+    impl<T: Add<Output = T> + PartialOrd + Default + Copy> SegmentTreeOperations for MaxSubarraySum<T> {
+        type Data = SubarrayNode<T>;
+
+        fn data_identity() -> Self::Data {
+            SubarrayNode {
+                non_empty: false,
+                ..SubarrayNode::new(T::default())
+            }
+        }
+
+        fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+            if !a.non_empty {
+                return *b;
+            }
+            if !b.non_empty {
+                return *a;
+            }
+            let max = |x: T, y: T| if y > x { y } else { x };
+            SubarrayNode {
+                sum: a.sum + b.sum,
+                prefix: max(a.prefix, a.sum + b.prefix),
+                suffix: max(b.suffix, a.suffix + b.sum),
+                best: max(max(a.best, b.best), a.suffix + b.prefix),
+                non_empty: true,
+            }
+        }
+    }
+
+    /// Range `a[i] = b * a[i] + c` (the lazy value is `(b, c)`), range sum.
+    pub struct AffineSum<T>(PhantomData<T>);
+    /// Range add, range sum.
+    pub struct AddSum<T>(PhantomData<T>);
+    /// Range assign (the lazy value is `Some(x)`), range sum.
+    pub struct AssignSum<T>(PhantomData<T>);
+    impl_monoid!(
+        AffineSum,
+        [Add<Output = T> + Default + Copy],
+        T::default(),
+        |a, b| *a + *b
+    );
+    impl_monoid!(
+        AddSum,
+        [Add<Output = T> + Default + Copy],
+        T::default(),
+        |a, b| *a + *b
+    );
+    impl_monoid!(
+        AssignSum,
+        [Add<Output = T> + Default + Copy],
+        T::default(),
+        |a, b| *a + *b
+    );
+
+    impl<T> LazySegmentTreeOperations for AffineSum<T>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Default + Copy + MultiplicativeIdentity + FromUsize,
+    {
+        type Lazy = (T, T);
+
+        fn lazy_identity() -> Self::Lazy {
+            (T::one(), T::default())
+        }
+
+        fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data {
+            update.0 * *data + update.1 * T::from_usize(r - l + 1)
+        }
+
+        fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+            (next.0 * prev.0, next.0 * prev.1 + next.1)
+        }
+    }
+
+    impl<T> LazySegmentTreeOperations for AddSum<T>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Default + Copy + MultiplicativeIdentity + FromUsize,
+    {
+        type Lazy = T;
+
+        fn lazy_identity() -> Self::Lazy {
+            T::default()
+        }
+
+        fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data {
+            *data + *update * T::from_usize(r - l + 1)
+        }
+
+        fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+            *prev + *next
+        }
+    }
+
+    impl<T> LazySegmentTreeOperations for AssignSum<T>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Default + Copy + MultiplicativeIdentity + FromUsize,
+    {
+        type Lazy = Option<T>;
+
+        fn lazy_identity() -> Self::Lazy {
+            None
+        }
+
+        fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data {
+            match update {
+                Some(x) => *x * T::from_usize(r - l + 1),
+                None => *data,
+            }
+        }
+
+        fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+            next.or(*prev)
+        }
+    }
+
+    /// Implements `LazySegmentTreeOperations` (range add and range assign) for the `min`/`max` operations, keeping
+    /// the identity nodes unchanged.
+    macro_rules! impl_lazy_min_max {
+        ($($add:ident, $assign:ident, $ops:ident, $identity:ident);*) => {
+            $(
+                impl<T: PartialOrd + Bounded + Copy> SegmentTreeOperations for $add<T> {
+                    type Data = T;
+
+                    fn data_identity() -> Self::Data {
+                        $ops::<T>::data_identity()
+                    }
+
+                    fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+                        $ops::<T>::merge(a, b)
+                    }
+                }
+
+                impl<T: PartialOrd + Bounded + Copy> SegmentTreeOperations for $assign<T> {
+                    type Data = T;
+
+                    fn data_identity() -> Self::Data {
+                        $ops::<T>::data_identity()
+                    }
+
+                    fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+                        $ops::<T>::merge(a, b)
+                    }
+                }
+
+                impl<T> LazySegmentTreeOperations for $add<T>
+                where
+                    T: PartialOrd + Bounded + Copy + Add<Output = T> + Default,
+                {
+                    type Lazy = T;
+
+                    fn lazy_identity() -> Self::Lazy {
+                        T::default()
+                    }
+
+                    fn apply(data: &Self::Data, update: &Self::Lazy, _: usize, _: usize) -> Self::Data {
+                        if *data == T::$identity() {
+                            *data
+                        } else {
+                            *data + *update
+                        }
+                    }
+
+                    fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+                        *prev + *next
+                    }
+                }
+
+                impl<T: PartialOrd + Bounded + Copy> LazySegmentTreeOperations for $assign<T> {
+                    type Lazy = Option<T>;
+
+                    fn lazy_identity() -> Self::Lazy {
+                        None
+                    }
+
+                    fn apply(data: &Self::Data, update: &Self::Lazy, _: usize, _: usize) -> Self::Data {
+                        match update {
+                            Some(x) if *data != T::$identity() => *x,
+                            _ => *data,
+                        }
+                    }
+
+                    fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+                        next.or(*prev)
+                    }
+                }
+            )*
+        };
+    }
+
+    /// Range add, range min.
+    pub struct AddMin<T>(PhantomData<T>);
+    /// Range assign (the lazy value is `Some(x)`), range min.
+    pub struct AssignMin<T>(PhantomData<T>);
+    /// Range add, range max.
+    pub struct AddMax<T>(PhantomData<T>);
+    /// Range assign (the lazy value is `Some(x)`), range max.
+    pub struct AssignMax<T>(PhantomData<T>);
+    impl_lazy_min_max!(
+        AddMin, AssignMin, Min, max_value;
+        AddMax, AssignMax, Max, min_value
+    );
+}
+use seg_tree_ops::*;
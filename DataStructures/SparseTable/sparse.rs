@@ -44,6 +44,57 @@ pub mod sparse {
         }
     }
 
+    /// Works for any associative operation (even non-idempotent or non-commutative ones, like sums or matrix products),
+    /// with a single call of `func` per query.
+    ///
+    /// For every level `h`, the array is split into blocks of size `2^(h + 1)`, and every block stores the merged
+    /// values from each index till the middle of the block. A range `[l, r]` is then exactly covered by the two halves
+    /// around the middle of the block at the highest set bit of `l ^ r`.
+    ///
+    /// The `merge` of a `SegmentTreeOperations` works as well: `DisjointSparseTable::new(&a, Ops::merge)`.
+    pub struct DisjointSparseTable<T: Clone, F: Fn(&T, &T) -> T> {
+        n: usize,
+        func: F,
+        table: Vec<Vec<T>>,
+    }
+
+    impl<T: Clone, F: Fn(&T, &T) -> T> DisjointSparseTable<T, F> {
+        pub fn new(a: &[T], func: F) -> Self {
+            let n = a.len();
+            let log = (n.next_power_of_two().trailing_zeros() as usize).max(1);
+            let mut table = vec![a.to_vec(); log];
+            for h in 1..log {
+                let half = 1 << h;
+                for mid in (half..n).step_by(half << 1) {
+                    for i in (mid - half..mid - 1).rev() {
+                        table[h][i] = func(&a[i], &table[h][i + 1]);
+                    }
+                    for i in mid + 1..n.min(mid + half) {
+                        table[h][i] = func(&table[h][i - 1], &a[i]);
+                    }
+                }
+            }
+
+            Self { n, func, table }
+        }
+
+        pub fn query<R: RangeBounds<usize>>(&self, range: R) -> T {
+            let (l, r) = super::get_inclusive_usize_bounds(&range, self.n);
+            assert!(
+                l <= r && r < self.n,
+                "Invalid range [{}, {}] for size {}",
+                l,
+                r,
+                self.n
+            );
+            if l == r {
+                return self.table[0][l].clone();
+            }
+            let h = (l ^ r).ilog2() as usize;
+            (self.func)(&self.table[h][l], &self.table[h][r])
+        }
+    }
+
     /// I is the index type
     pub struct RMQ<
         T: PartialOrd + Copy + Clone,
@@ -0,0 +1,315 @@
+/// # Wavelet Matrix ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/DataStructures/WaveletMatrix/wavelet_matrix.rs))
+///
+/// Order statistics over a static array, every query in `O(log(max value))`.
+///
+/// All the value ranges are half-open: `range_freq(range, lo, hi)` counts the values in `[lo, hi)`. The positions are
+/// a `RangeBounds` (like in the segment trees) instead of `l, r`, so `range_freq(l, r, lo, hi)` is written as
+/// `range_freq(l..=r, lo, hi)`.
+///
+/// You'll need the module [my_utils.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/CombinedRustModules/my_utils.rs) to use the same.
+pub mod wavelet_matrix {
+    use std::ops::RangeBounds;
+
+    /// Bits with `O(1)` rank.
+    struct BitVector {
+        blocks: Vec<u64>,
+        /// `ones[i]` is the number of ones in the first `i` blocks.
+        ones: Vec<u32>,
+    }
+
+    impl BitVector {
+        fn new(bits: &[bool]) -> Self {
+            let mut blocks = vec![0u64; bits.len() / 64 + 1];
+            for (i, _) in bits.iter().enumerate().filter(|(_, &bit)| bit) {
+                blocks[i >> 6] |= 1 << (i & 63);
+            }
+            let mut ones = vec![0; blocks.len() + 1];
+            for i in 0..blocks.len() {
+                ones[i + 1] = ones[i] + blocks[i].count_ones();
+            }
+            Self { blocks, ones }
+        }
+
+        fn get(&self, i: usize) -> bool {
+            self.blocks[i >> 6] >> (i & 63) & 1 == 1
+        }
+
+        /// The number of ones in `[0, i)`.
+        fn rank1(&self, i: usize) -> usize {
+            let below = self.blocks[i >> 6] & ((1u64 << (i & 63)) - 1);
+            (self.ones[i >> 6] + below.count_ones()) as usize
+        }
+
+        /// The number of zeros in `[0, i)`.
+        fn rank0(&self, i: usize) -> usize {
+            i - self.rank1(i)
+        }
+    }
+
+    pub struct WaveletMatrix {
+        /// The number of values passed during construction.
+        pub n: usize,
+
+        /// The number of bits of the largest value.
+        log: usize,
+
+        /// `levels[d]` has the bit `log - 1 - d` of the values, stably sorted by the higher bits (the zeros first).
+        levels: Vec<BitVector>,
+
+        /// The number of zeros in every level.
+        zeros: Vec<usize>,
+    }
+
+    impl WaveletMatrix {
+        pub fn new(values: &[u64]) -> Self {
+            let n = values.len();
+            let max = values.iter().copied().max().unwrap_or(0);
+            let log = (u64::BITS - max.leading_zeros()) as usize;
+            let mut levels = Vec::with_capacity(log);
+            let mut zeros = Vec::with_capacity(log);
+
+            let mut cur = values.to_vec();
+            for h in (0..log).rev() {
+                let bits: Vec<bool> = cur.iter().map(|&x| x >> h & 1 == 1).collect();
+                let (mut zero, one): (Vec<u64>, Vec<u64>) =
+                    cur.iter().partition(|&&x| x >> h & 1 == 0);
+                levels.push(BitVector::new(&bits));
+                zeros.push(zero.len());
+                zero.extend(one);
+                cur = zero;
+            }
+
+            Self {
+                n,
+                log,
+                levels,
+                zeros,
+            }
+        }
+
+        /// The half-open range of the positions.
+        fn bounds<R: RangeBounds<usize>>(&self, range: &R) -> (usize, usize) {
+            // The bounds are resolved against `n`, which would underflow for an empty matrix
+            if self.n == 0 {
+                return (0, 0);
+            }
+            let (l, r) = super::get_inclusive_usize_bounds(range, self.n);
+            assert!(
+                r < self.n || l > r,
+                "Index {} out of bounds for size {}",
+                r,
+                self.n
+            );
+            (l, (r + 1).max(l))
+        }
+
+        /// Follows the positions `[l, r)` to the next level, to the values with the `bit`.
+        fn descend(&self, d: usize, l: usize, r: usize, bit: bool) -> (usize, usize) {
+            let level = &self.levels[d];
+            if bit {
+                (
+                    self.zeros[d] + level.rank1(l),
+                    self.zeros[d] + level.rank1(r),
+                )
+            } else {
+                (level.rank0(l), level.rank0(r))
+            }
+        }
+
+        /// The value at `pos`.
+        pub fn get(&self, mut pos: usize) -> u64 {
+            assert!(
+                pos < self.n,
+                "Index {} out of bounds for size {}",
+                pos,
+                self.n
+            );
+            let mut value = 0;
+            for d in 0..self.log {
+                let bit = self.levels[d].get(pos);
+                value = value << 1 | bit as u64;
+                pos = self.descend(d, pos, pos, bit).0;
+            }
+            value
+        }
+
+        /// The `k`-th (0-based) smallest value in `range`.
+        pub fn kth_smallest<R>(&self, range: R, mut k: usize) -> u64
+        where
+            R: RangeBounds<usize>,
+        {
+            let (mut l, mut r) = self.bounds(&range);
+            assert!(k < r - l, "k = {} for a range of length {}", k, r - l);
+            let mut value = 0;
+            for d in 0..self.log {
+                let zeros = self.levels[d].rank0(r) - self.levels[d].rank0(l);
+                let bit = k >= zeros;
+                if bit {
+                    k -= zeros;
+                }
+                value = value << 1 | bit as u64;
+                (l, r) = self.descend(d, l, r, bit);
+            }
+            value
+        }
+
+        /// The `k`-th (0-based) largest value in `range`.
+        pub fn kth_largest<R>(&self, range: R, k: usize) -> u64
+        where
+            R: RangeBounds<usize>,
+        {
+            let (l, r) = self.bounds(&range);
+            assert!(k < r - l, "k = {} for a range of length {}", k, r - l);
+            self.kth_smallest(l..r, r - l - 1 - k)
+        }
+
+        /// The number of occurrences of `x` in `range`.
+        pub fn rank<R>(&self, range: R, x: u64) -> usize
+        where
+            R: RangeBounds<usize>,
+        {
+            let (mut l, mut r) = self.bounds(&range);
+            if self.log < 64 && x >> self.log != 0 {
+                return 0;
+            }
+            for d in 0..self.log {
+                (l, r) = self.descend(d, l, r, x >> (self.log - 1 - d) & 1 == 1);
+            }
+            r - l
+        }
+
+        /// The number of values smaller than `x` in the positions `[l, r)`.
+        fn count_less(&self, mut l: usize, mut r: usize, x: u64) -> usize {
+            if self.log < 64 && x >> self.log != 0 {
+                return r - l;
+            }
+            let mut count = 0;
+            for d in 0..self.log {
+                let bit = x >> (self.log - 1 - d) & 1 == 1;
+                if bit {
+                    count += self.levels[d].rank0(r) - self.levels[d].rank0(l);
+                }
+                (l, r) = self.descend(d, l, r, bit);
+            }
+            count
+        }
+
+        /// The number of values in `[lo, hi)` in `range` (e.g. `l..=r` for the positions `[l, r]`).
+        pub fn range_freq<R>(&self, range: R, lo: u64, hi: u64) -> usize
+        where
+            R: RangeBounds<usize>,
+        {
+            let (l, r) = self.bounds(&range);
+            if lo >= hi {
+                return 0;
+            }
+            self.count_less(l, r, hi) - self.count_less(l, r, lo)
+        }
+
+        /// The largest value smaller than `x` in `range`.
+        pub fn prev_value<R>(&self, range: R, x: u64) -> Option<u64>
+        where
+            R: RangeBounds<usize>,
+        {
+            let (l, r) = self.bounds(&range);
+            match self.count_less(l, r, x) {
+                0 => None,
+                count => Some(self.kth_smallest(l..r, count - 1)),
+            }
+        }
+
+        /// The smallest value not smaller than `x` in `range`.
+        pub fn next_value<R>(&self, range: R, x: u64) -> Option<u64>
+        where
+            R: RangeBounds<usize>,
+        {
+            let (l, r) = self.bounds(&range);
+            let count = self.count_less(l, r, x);
+            (count < r - l).then(|| self.kth_smallest(l..r, count))
+        }
+    }
+
+    /// [WaveletMatrix] over any `Ord` values, using `coordinate_compression`.
+    pub struct CompressedWaveletMatrix<T: Ord + Clone> {
+        matrix: WaveletMatrix,
+        /// The sorted distinct values.
+        values: Vec<T>,
+    }
+
+    impl<T: Ord + Clone> CompressedWaveletMatrix<T> {
+        pub fn new(a: &[T]) -> Self {
+            let (compressed, values) = super::coordinate_compression(a);
+            let compressed: Vec<u64> = compressed.into_iter().map(|x| x as u64).collect();
+            Self {
+                matrix: WaveletMatrix::new(&compressed),
+                values,
+            }
+        }
+
+        /// The number of distinct values smaller than `x`, i.e. the compressed value of `x` (or the next one).
+        fn lower_bound(&self, x: &T) -> u64 {
+            self.values.partition_point(|y| y < x) as u64
+        }
+
+        pub fn get(&self, pos: usize) -> T {
+            self.values[self.matrix.get(pos) as usize].clone()
+        }
+
+        /// The `k`-th (0-based) smallest value in `range`.
+        pub fn kth_smallest<R>(&self, range: R, k: usize) -> T
+        where
+            R: RangeBounds<usize>,
+        {
+            self.values[self.matrix.kth_smallest(range, k) as usize].clone()
+        }
+
+        /// The `k`-th (0-based) largest value in `range`.
+        pub fn kth_largest<R>(&self, range: R, k: usize) -> T
+        where
+            R: RangeBounds<usize>,
+        {
+            self.values[self.matrix.kth_largest(range, k) as usize].clone()
+        }
+
+        /// The number of occurrences of `x` in `range`.
+        pub fn rank<R>(&self, range: R, x: &T) -> usize
+        where
+            R: RangeBounds<usize>,
+        {
+            match self.values.binary_search(x) {
+                Ok(i) => self.matrix.rank(range, i as u64),
+                Err(_) => 0,
+            }
+        }
+
+        /// The number of values in `[lo, hi)` in `range`.
+        pub fn range_freq<R>(&self, range: R, lo: &T, hi: &T) -> usize
+        where
+            R: RangeBounds<usize>,
+        {
+            self.matrix
+                .range_freq(range, self.lower_bound(lo), self.lower_bound(hi))
+        }
+
+        /// The largest value smaller than `x` in `range`.
+        pub fn prev_value<R>(&self, range: R, x: &T) -> Option<T>
+        where
+            R: RangeBounds<usize>,
+        {
+            self.matrix
+                .prev_value(range, self.lower_bound(x))
+                .map(|i| self.values[i as usize].clone())
+        }
+
+        /// The smallest value not smaller than `x` in `range`.
+        pub fn next_value<R>(&self, range: R, x: &T) -> Option<T>
+        where
+            R: RangeBounds<usize>,
+        {
+            self.matrix
+                .next_value(range, self.lower_bound(x))
+                .map(|i| self.values[i as usize].clone())
+        }
+    }
+}
+use wavelet_matrix::*;
@@ -14,7 +14,9 @@ pub mod centroid_decomposition {
         // pub parent: Vec<u32>,
         // /// depth\[[root]] = 0
         // pub depth: Vec<u32>,
-        // pub size: Vec<u32>,
+        /// Used while finding the centroids, `size[u]` is the size of the subtree of `u` in the last component it was
+        /// part of.
+        pub size: Vec<u32>,
     }
 
     impl CentroidTree {
@@ -22,10 +24,10 @@ pub mod centroid_decomposition {
             let mut centroid_tree = Self {
                 n: tree.n,
                 root: 0, // Any value
-                         // children: vec![vec![]; tree.n],
-                         // parent: vec![0; tree.n],
-                         // depth: vec![0; tree.n],
-                         // size: vec![0; tree.n],
+                // children: vec![vec![]; tree.n],
+                // parent: vec![0; tree.n],
+                // depth: vec![0; tree.n],
+                size: vec![0; tree.n],
             };
 
             let mut centroid_taken = vec![false; tree.n];
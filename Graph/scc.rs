@@ -3,6 +3,7 @@
 /// You'll need the module [graph.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/Graph/graph.rs) to use the same.
 pub mod scc {
     use super::graph::*;
+    use std::marker::PhantomData;
 
     pub struct SCC<T: Clone + Eq + PartialEq + Default> {
         pub n: usize,
@@ -11,6 +12,8 @@ pub mod scc {
         pub group: Vec<u32>,
         // /// Might contain parallel edges
         // pub condensed_graph: Graph<T, true>,
+        /// The weight type of the graph, only needed while the condensed graph is commented out.
+        weight: PhantomData<T>,
     }
 
     impl<T: Clone + Eq + PartialEq + Default> SCC<T> {
@@ -31,6 +34,7 @@ pub mod scc {
                 components: Vec::new(),
                 group: vec![0; g.n],
                 // condensed_graph: Graph::new(g.n, g.edges.len()),
+                weight: PhantomData,
             };
             scc.init(adj, rev_adj);
 
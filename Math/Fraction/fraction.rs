@@ -58,14 +58,21 @@ pub mod fraction {
     }
 
     impl<T: FractionOperationType, OpT: FractionOperationType + From<T> + TryInto<T>> Fraction<T, OpT> {
+        /// Reduces the fraction to its lowest terms, with a positive denominator.
         pub fn new<U: Into<T>>(num: U, den: U) -> Fraction<T, OpT> {
             let num = num.into();
             let den = den.into();
             let g = super::gcd(num, den);
             assert!(den != T::default(), "Denominator cannot be zero!");
+            let (mut num, mut den) = (num / g, den / g);
+            // Keeping the denominator positive, the comparison depends on it
+            if den < T::default() {
+                num = T::default() - num;
+                den = T::default() - den;
+            }
             Self {
-                num: num / g,
-                den: den / g,
+                num,
+                den,
                 phantom: PhantomData,
             }
         }
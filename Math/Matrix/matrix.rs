@@ -39,6 +39,7 @@ pub mod matrix {
             mat
         }
 
+        /// Same as `pow` in math.rs, the base isn't squared after the highest bit of `exp`.
         pub fn pow<P>(&self, mut exp: P) -> Matrix<T>
         where
             T: Mul<Output = T> + super::MultiplicativeIdentity + AddAssign,
@@ -55,8 +56,11 @@ pub mod matrix {
                 if (exp & P::from(1u8)) == P::from(1u8) {
                     res *= &base;
                 }
-                base = &base * &base;
                 exp >>= P::from(1u8);
+                // Not squaring after the last bit, as it might overflow even if the answer doesn't
+                if exp > P::default() {
+                    base = &base * &base;
+                }
             }
             res
         }
@@ -81,7 +85,7 @@ pub mod matrix {
 
         fn add(self, rhs: &Matrix<T>) -> Self::Output {
             let mut ans = self.clone();
-            ans += &rhs;
+            ans += rhs;
             ans
         }
     }
@@ -119,7 +123,7 @@ pub mod matrix {
 
         fn sub(self, rhs: &Matrix<T>) -> Self::Output {
             let mut ans = self.clone();
-            ans -= &rhs;
+            ans -= rhs;
             ans
         }
     }
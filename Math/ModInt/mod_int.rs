@@ -167,6 +167,15 @@ pub mod mod_int {
         }
     }
 
+    impl<M: Modulo> FromUsize for ModInt<M>
+    where
+        M::OpT: FromUsize,
+    {
+        fn from_usize(x: usize) -> Self {
+            Self::new(M::OpT::from_usize(x))
+        }
+    }
+
     impl<M: Modulo> MultiplicativeInverse for ModInt<M> {
         type Output = ModInt<M>;
 
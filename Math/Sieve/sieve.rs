@@ -52,6 +52,7 @@ pub mod sieve {
             self.is_prime[n]
         }
 
+        /// Trial division by the primes up to `N`, for any `n <= N * N` (including the primes up to `N` themselves).
         pub fn is_prime_big<T>(&self, n: T) -> bool
         where
             T: TryInto<u64>,
@@ -64,9 +65,12 @@ pub mod sieve {
                 n,
                 N * N
             );
+            if n < 2 {
+                return false;
+            }
             for &p in &self.primes {
                 if n % p as u64 == 0 {
-                    return false;
+                    return n == p as u64;
                 }
             }
             true
@@ -40,8 +40,7 @@ pub mod polynomial_hash {
         }
 
         pub fn get_hash(&self, l: usize, r: usize) -> ModInt<M> {
-            let h = (self.hash_pre[r + 1] - self.hash_pre[l]) * self.inv_pow_b[l];
-            h
+            (self.hash_pre[r + 1] - self.hash_pre[l]) * self.inv_pow_b[l]
         }
     }
 
@@ -6,54 +6,65 @@
 
 #![allow(unused_variables)]
 #![allow(unused_imports)]
-use std::io::{BufWriter, StdoutLock, Write};
+#![allow(dead_code)]
+#![allow(unused_macros)]
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 
+/// Set when the `-DEBUG` argument is passed (or by the tests).
+pub static DEBUG: AtomicBool = AtomicBool::new(false);
+
+fn debug_mode() -> bool {
+    DEBUG.load(Ordering::Relaxed)
+}
+
+/// Similar to `dbg!()` (and the `debug` function in the Java template), but prints only in the debug mode.
+///
+/// `debug!(n, a[i]);` prints `#<line>: n = 5; a[i] = 3;` to stderr.
+macro_rules! debug {
+    ($($x:expr),* $(,)?) => {
+        if debug_mode() {
+            eprint!("#{}:", line!());
+            $(eprint!(" {} = {:?};", stringify!($x), &$x);)*
+            eprintln!();
+        }
+    };
+}
+
 fn main() {
     let builder = thread::Builder::new()
         .name("main".into())
         .stack_size(1 << 28);
     builder
         .spawn(|| {
+            let arg = std::env::args().nth(1);
+            if arg.as_deref() == Some("-STRESS") {
+                stress_test();
+                return;
+            }
+            DEBUG.store(arg.is_some_and(|x| x == "-DEBUG"), Ordering::Relaxed);
             let sc = Scanner::new();
-            let out = BufWriter::with_capacity(custom_io::IO_BUF_SIZE, std::io::stdout().lock());
+            let out = if Scanner::INTERACTIVE {
+                Writer::interactive(std::io::stdout().lock())
+            } else {
+                Writer::new(std::io::stdout().lock())
+            };
             let mut sol = Solution::with_io(sc, out);
 
             loop {
-                sol.solve();
-                // let testcases: i32 = sol.sc.input();
-                // for testcase in 1..=testcases {
-                //     // dbg!("......", testcase);
-                //
-                //     // write!(sol.out, "Case #{}: ", testcase).unwrap();
-                //     let ans = sol.solve();
-                //     // writeln!(sol.out, "{}", ans).unwrap();
-                //
-                //     // if ans {
-                //     //     writeln!(sol.out, "Yes").unwrap();
-                //     // } else {
-                //     //     writeln!(sol.out, "No").unwrap();
-                //     // }
-                //
-                //     // for x in ans {
-                //     //     write!(sol.out, "{} ", x).unwrap();
-                //     //     writeln!(sol.out).unwrap();
-                //     // }
-                // }
+                sol.run();
 
-                sol.sc.skip_whitespaces();
-                if sol.sc.stored_next_byte.is_none() {
+                // Peeking would block an interactive scanner, so the input is checked for more only in the debug mode
+                if !debug_mode() {
                     break;
                 }
-                match std::env::args().collect::<Vec<_>>().get(1) {
-                    Some(val) if *val == "-DEBUG".to_string() => {
-                        writeln!(sol.out, "Next Input:").unwrap();
-                        dbg!("Next Input:");
-                    }
-                    _ => {
-                        break;
-                    }
+                sol.sc.skip_whitespaces();
+                if sol.sc.is_eof() {
+                    break;
                 }
+                writeln!(sol.out, "Next Input:").unwrap();
+                eprintln!("Next Input:");
             }
         })
         .unwrap()
@@ -62,55 +73,261 @@ fn main() {
 }
 
 pub mod custom_io {
-    use std::io::{BufRead, BufReader, StdinLock};
+    use std::io::{BufRead, BufReader, Read, StdinLock, Write};
 
     /// This is used in both input and output (check the main function).
     pub const IO_BUF_SIZE: usize = 1 << 16;
 
-    /// This is not tested on UTF-8, only ASCII.
-    pub struct Scanner<'a> {
-        reader: BufReader<StdinLock<'a>>,
-        input_helper: String,
+    /// The API shared by all the scanners, so that they can be swapped using the `Scanner` type alias.
+    ///
+    /// Only the methods without a default implementation depend on how the input is stored.
+    pub trait ScannerOperations {
+        /// Interactive scanners make the [Writer] flush after every line (check the main function).
+        const INTERACTIVE: bool = false;
+
+        /// The next unread byte, `None` if the input has ended.
+        fn peek_byte(&mut self) -> Option<u8>;
+
+        /// Consumes and returns the next unread byte.
+        fn next_byte(&mut self) -> Option<u8>;
+
+        /// The number of bytes consumed till now, i.e. the position of the next unread byte in the input.
+        fn offset(&self) -> usize;
+
+        /// Skips the whitespaces and returns the bytes of the next token (which should exist).
+        ///
+        /// The tokens are separated by ASCII whitespaces, which never occur inside a multibyte UTF-8 character.
+        fn next_token_bytes(&mut self) -> &[u8];
+
+        /// Same as `input`, but only for integers.
+        ///
+        /// The digits are parsed directly from the stored input, without building a `String`.
+        fn input_int<T: InputInteger>(&mut self) -> T;
+
+        fn skip_whitespaces(&mut self) {
+            while let Some(b) = self.peek_byte() {
+                if b.is_ascii_whitespace() {
+                    self.next_byte();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        /// Note that this doesn't skip the whitespaces, so call `skip_whitespaces` before it.
+        fn is_eof(&mut self) -> bool {
+            self.peek_byte().is_none()
+        }
+
+        /// Same as `next_token_bytes`, but the token has to be valid UTF-8.
+        fn next_token(&mut self) -> &str {
+            std::str::from_utf8(self.next_token_bytes()).expect("Failed to parse input")
+        }
+
+        /// Reads the next token using `FromStr`.
+        ///
+        /// Without specialization, this (and `input_vec`, `input_tuple`, ...) can't tell the integers apart from the
+        /// rest of the types, so they aren't parsed using the faster `input_int`. Call `input_int` (like
+        /// `(0..n).map(|_| sc.input_int()).collect()`) for those.
+        fn input<T: std::str::FromStr>(&mut self) -> T
+        where
+            T::Err: std::fmt::Debug,
+        {
+            self.try_input().unwrap_or_else(|e| panic!("{}", e))
+        }
+
+        /// Same as `input`, but returns an error instead of panicking.
+        fn try_input<T: std::str::FromStr>(&mut self) -> Result<T, ScanError>
+        where
+            T::Err: std::fmt::Debug,
+        {
+            self.skip_whitespaces();
+            if self.is_eof() {
+                return Err(ScanError::Eof);
+            }
+
+            let offset = self.offset();
+            let token = self.next_token_bytes();
+            std::str::from_utf8(token)
+                .map_err(|e| format!("{:?}", e))
+                .and_then(|t| t.parse::<T>().map_err(|e| format!("{:?}", e)))
+                .map_err(|error| ScanError::Parse {
+                    token: String::from_utf8_lossy(token).into_owned(),
+                    offset,
+                    error,
+                })
+        }
+
+        /// The bytes of the next token, without requiring them to be valid UTF-8.
+        fn input_bytes(&mut self) -> Vec<u8> {
+            self.next_token_bytes().to_vec()
+        }
+
+        fn input_chars(&mut self) -> Vec<char> {
+            self.next_token().chars().collect()
+        }
+
+        /// Iterator over the remaining tokens, ending at EOF (useful when the number of tokens isn't given).
+        ///
+        /// It still panics if a token can't be parsed.
+        fn tokens<T: std::str::FromStr>(&mut self) -> Tokens<'_, Self, T>
+        where
+            T::Err: std::fmt::Debug,
+            Self: Sized,
+        {
+            Tokens {
+                sc: self,
+                phantom: std::marker::PhantomData,
+            }
+        }
+
+        /// Skips the whitespaces (including empty lines), and reads till the end of the line.
+        ///
+        /// The line can end with either `\n` or `\r\n`, and the trailing whitespaces are removed.
+        fn input_line(&mut self) -> String {
+            self.skip_whitespaces();
+            assert!(!self.is_eof(), "Input not found!");
+
+            let mut line = Vec::new();
+            while let Some(c) = self.next_byte() {
+                if c == b'\n' {
+                    break;
+                }
+                line.push(c);
+            }
+            String::from_utf8(line)
+                .expect("Failed to parse input")
+                .trim_end()
+                .to_string()
+        }
+
+        fn input_vec<T: std::str::FromStr>(&mut self, n: usize) -> Vec<T>
+        where
+            T::Err: std::fmt::Debug,
+        {
+            (0..n).map(|_| self.input()).collect()
+        }
+
+        /// Reads `n` values and maps each of them using `map`.
+        fn input_vec_with<T, U, F>(&mut self, n: usize, mut map: F) -> Vec<U>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Debug,
+            F: FnMut(T) -> U,
+        {
+            (0..n).map(|_| map(self.input())).collect()
+        }
+
+        fn input_matrix<T: std::str::FromStr>(&mut self, h: usize, w: usize) -> Vec<Vec<T>>
+        where
+            T::Err: std::fmt::Debug,
+        {
+            (0..h).map(|_| self.input_vec(w)).collect()
+        }
+
+        /// Reads `h` tokens, each of them having exactly `w` bytes (like `#..#.`).
+        fn input_grid_bytes(&mut self, h: usize, w: usize) -> Vec<Vec<u8>> {
+            (0..h)
+                .map(|_| {
+                    let row = self.input_bytes();
+                    assert_eq!(row.len(), w, "Grid row of unexpected length!");
+                    row
+                })
+                .collect()
+        }
+
+        /// `let (n, m, k) = sc.input_tuple::<(usize, usize, i64)>();`
+        fn input_tuple<T: InputTuple>(&mut self) -> T
+        where
+            Self: Sized,
+        {
+            T::input_from(self)
+        }
+
+        /// Reads a 1-based index and converts it to 0-based.
+        fn input_idx(&mut self) -> usize {
+            self.input_int::<usize>() - 1
+        }
+
+        /// Reads `n` 1-based indices and converts them to 0-based.
+        fn input_vec_idx(&mut self, n: usize) -> Vec<usize> {
+            (0..n).map(|_| self.input_idx()).collect()
+        }
+
+        /// Reads `m` edges `u v` given as 1-based indices, and converts them to 0-based.
+        fn input_edges(&mut self, m: usize) -> Vec<(usize, usize)> {
+            (0..m)
+                .map(|_| (self.input_idx(), self.input_idx()))
+                .collect()
+        }
+
+        /// Reads `m` edges `u v w` with `u` and `v` given as 1-based indices, and converts them to 0-based.
+        fn input_weighted_edges<W: std::str::FromStr>(&mut self, m: usize) -> Vec<(usize, usize, W)>
+        where
+            W::Err: std::fmt::Debug,
+        {
+            (0..m)
+                .map(|_| (self.input_idx(), self.input_idx(), self.input()))
+                .collect()
+        }
+    }
+
+    /// Reads the input in chunks of `IO_BUF_SIZE` bytes (for the stdin).
+    ///
+    /// Works with any `BufRead`, like `&[u8]` for the input in memory.
+    pub struct StreamScanner<R: BufRead> {
+        reader: R,
+        input_helper: Vec<u8>,
         pub stored_next_byte: Option<u8>,
+        /// The position of `stored_next_byte` in the input.
+        offset: usize,
     }
-    impl<'a> Scanner<'a> {
+    impl StreamScanner<BufReader<StdinLock<'static>>> {
         pub fn new() -> Self {
-            let mut reader = BufReader::with_capacity(IO_BUF_SIZE, std::io::stdin().lock());
-            let first_byte = reader.fill_buf().unwrap().get(0).copied();
+            Self::from_reader(BufReader::with_capacity(
+                IO_BUF_SIZE,
+                std::io::stdin().lock(),
+            ))
+        }
+    }
+    impl<R: BufRead> StreamScanner<R> {
+        pub fn from_reader(mut reader: R) -> Self {
+            let first_byte = reader.fill_buf().unwrap().first().copied();
             if first_byte.is_some() {
                 reader.consume(1);
             }
             Self {
                 reader,
-                input_helper: String::with_capacity(32),
+                input_helper: Vec::with_capacity(32),
                 stored_next_byte: first_byte,
+                offset: 0,
             }
         }
+    }
+    impl<R: BufRead> ScannerOperations for StreamScanner<R> {
+        fn peek_byte(&mut self) -> Option<u8> {
+            self.stored_next_byte
+        }
 
         // BufReader::peek() and BufReader::has_data_left() are not available yet
         fn next_byte(&mut self) -> Option<u8> {
             let b = self.stored_next_byte;
-            self.stored_next_byte = self.reader.fill_buf().unwrap().get(0).copied();
-            if b.is_some() {
+            self.stored_next_byte = self.reader.fill_buf().unwrap().first().copied();
+            // Consuming past the end is only ignored by some readers (like BufReader)
+            if self.stored_next_byte.is_some() {
                 self.reader.consume(1);
             }
+            if b.is_some() {
+                self.offset += 1;
+            }
             b
         }
 
-        pub fn skip_whitespaces(&mut self) {
-            while let Some(b) = self.stored_next_byte {
-                if b.is_ascii_whitespace() {
-                    self.next_byte();
-                } else {
-                    break;
-                }
-            }
+        fn offset(&self) -> usize {
+            self.offset
         }
 
-        pub fn input<T: std::str::FromStr>(&mut self) -> T
-        where
-            T::Err: std::fmt::Debug,
-        {
+        fn next_token_bytes(&mut self) -> &[u8] {
             self.skip_whitespaces();
             assert!(self.stored_next_byte.is_some(), "Input not found!");
 
@@ -119,39 +336,731 @@ pub mod custom_io {
                 if c.is_ascii_whitespace() {
                     break;
                 }
-                self.input_helper.push(c as char);
+                self.input_helper.push(c);
             }
+            &self.input_helper
+        }
 
-            self.input_helper
-                .parse::<T>()
-                .expect("Failed to parse input")
+        fn input_int<T: InputInteger>(&mut self) -> T {
+            self.skip_whitespaces();
+            let first = self.stored_next_byte.expect("Input not found!");
+            let negative = first == b'-';
+            assert!(T::SIGNED || !negative, "Failed to parse input");
+
+            let mut val = T::zero();
+            let mut digits = 0;
+            if first.is_ascii_digit() {
+                val = val.push_digit(first - b'0', false);
+                digits += 1;
+            } else {
+                assert!(negative || first == b'+', "Failed to parse input");
+            }
+            // The first byte was already consumed from the reader
+            self.offset += 1;
+
+            loop {
+                let buf = self.reader.fill_buf().unwrap();
+                if buf.is_empty() {
+                    self.stored_next_byte = None;
+                    break;
+                }
+
+                let mut i = 0;
+                while i < buf.len() && buf[i].is_ascii_digit() {
+                    val = val.push_digit(buf[i] - b'0', negative);
+                    i += 1;
+                }
+                digits += i;
+                self.offset += i;
+                if i < buf.len() {
+                    self.stored_next_byte = Some(buf[i]);
+                    self.reader.consume(i + 1);
+                    break;
+                }
+                let len = buf.len();
+                self.reader.consume(len);
+            }
+
+            assert!(
+                digits > 0
+                    && self
+                        .stored_next_byte
+                        .map_or(true, |b| b.is_ascii_whitespace()),
+                "Failed to parse input"
+            );
+            val
+        }
+    }
+
+    /// Reads the whole input into memory at once, and then moves a cursor over it.
+    ///
+    /// Faster than [StreamScanner], but the whole input has to fit in the memory.
+    pub struct WholeInputScanner {
+        input: Vec<u8>,
+        pos: usize,
+    }
+    impl WholeInputScanner {
+        pub fn new() -> Self {
+            Self::from_reader(std::io::stdin().lock())
+        }
+
+        pub fn from_reader<R: Read>(mut reader: R) -> Self {
+            let mut input = Vec::with_capacity(IO_BUF_SIZE);
+            reader.read_to_end(&mut input).unwrap();
+            Self::from_bytes(input)
+        }
+
+        /// Reads from memory instead of the stdin (used by the stress tests).
+        pub fn from_bytes(input: Vec<u8>) -> Self {
+            Self { input, pos: 0 }
+        }
+    }
+    impl ScannerOperations for WholeInputScanner {
+        fn peek_byte(&mut self) -> Option<u8> {
+            self.input.get(self.pos).copied()
+        }
+
+        fn next_byte(&mut self) -> Option<u8> {
+            let b = self.peek_byte();
+            if b.is_some() {
+                self.pos += 1;
+            }
+            b
         }
 
-        pub fn input_line(&mut self) -> String {
+        fn offset(&self) -> usize {
+            self.pos
+        }
+
+        fn next_token_bytes(&mut self) -> &[u8] {
             self.skip_whitespaces();
-            assert!(self.stored_next_byte.is_some(), "Input not found!");
+            assert!(self.pos < self.input.len(), "Input not found!");
+
+            let start = self.pos;
+            while self.pos < self.input.len() && !self.input[self.pos].is_ascii_whitespace() {
+                self.pos += 1;
+            }
+            &self.input[start..self.pos]
+        }
+
+        fn input_int<T: InputInteger>(&mut self) -> T {
+            self.skip_whitespaces();
+            let first = self.peek_byte().expect("Input not found!");
+            let negative = first == b'-';
+            assert!(T::SIGNED || !negative, "Failed to parse input");
+            if negative || first == b'+' {
+                self.pos += 1;
+            }
+
+            let start = self.pos;
+            let mut val = T::zero();
+            while self.pos < self.input.len() && self.input[self.pos].is_ascii_digit() {
+                val = val.push_digit(self.input[self.pos] - b'0', negative);
+                self.pos += 1;
+            }
+
+            assert!(
+                self.pos > start && self.peek_byte().map_or(true, |b| b.is_ascii_whitespace()),
+                "Failed to parse input"
+            );
+            val
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub enum ScanError {
+        /// The input has ended.
+        Eof,
+        /// The token starting at the byte `offset` couldn't be parsed.
+        Parse {
+            token: String,
+            offset: usize,
+            error: String,
+        },
+    }
+    impl std::fmt::Display for ScanError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ScanError::Eof => write!(f, "Input not found!"),
+                ScanError::Parse {
+                    token,
+                    offset,
+                    error,
+                } => write!(
+                    f,
+                    "Failed to parse input {:?} at byte {}: {}",
+                    token, offset, error
+                ),
+            }
+        }
+    }
+    impl std::error::Error for ScanError {}
+
+    pub struct Tokens<'a, S: ScannerOperations, T> {
+        sc: &'a mut S,
+        phantom: std::marker::PhantomData<T>,
+    }
+    impl<'a, S: ScannerOperations, T: std::str::FromStr> Iterator for Tokens<'a, S, T>
+    where
+        T::Err: std::fmt::Debug,
+    {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            match self.sc.try_input() {
+                Ok(x) => Some(x),
+                Err(ScanError::Eof) => None,
+                Err(e) => panic!("{}", e),
+            }
+        }
+    }
+
+    /// For interactive problems, it never reads ahead of the current line (so it doesn't wait for the judge).
+    ///
+    /// Unlike [StreamScanner], the next byte is only looked at when it's required.
+    pub struct InteractiveScanner<R: BufRead> {
+        reader: R,
+        input_helper: Vec<u8>,
+        offset: usize,
+    }
+    impl InteractiveScanner<StdinLock<'static>> {
+        pub fn new() -> Self {
+            Self::from_reader(std::io::stdin().lock())
+        }
+    }
+    impl<R: BufRead> InteractiveScanner<R> {
+        pub fn from_reader(reader: R) -> Self {
+            Self {
+                reader,
+                input_helper: Vec::with_capacity(32),
+                offset: 0,
+            }
+        }
+    }
+    impl<R: BufRead> ScannerOperations for InteractiveScanner<R> {
+        const INTERACTIVE: bool = true;
+
+        fn peek_byte(&mut self) -> Option<u8> {
+            self.reader.fill_buf().unwrap().first().copied()
+        }
+
+        fn next_byte(&mut self) -> Option<u8> {
+            let b = self.peek_byte();
+            if b.is_some() {
+                self.reader.consume(1);
+                self.offset += 1;
+            }
+            b
+        }
+
+        fn offset(&self) -> usize {
+            self.offset
+        }
+
+        /// The whitespace after the token is not consumed, as it might be the end of the line.
+        fn next_token_bytes(&mut self) -> &[u8] {
+            self.skip_whitespaces();
+            assert!(!self.is_eof(), "Input not found!");
 
             self.input_helper.clear();
-            while let Some(c) = self.next_byte() {
-                if c == b'\n' || c == b'\r' {
+            while let Some(c) = self.peek_byte() {
+                if c.is_ascii_whitespace() {
+                    break;
+                }
+                self.input_helper.push(c);
+                self.next_byte();
+            }
+            &self.input_helper
+        }
+
+        fn input_int<T: InputInteger>(&mut self) -> T {
+            self.skip_whitespaces();
+            let first = self.peek_byte().expect("Input not found!");
+            let negative = first == b'-';
+            assert!(T::SIGNED || !negative, "Failed to parse input");
+            if negative || first == b'+' {
+                self.next_byte();
+            }
+
+            let mut val = T::zero();
+            let mut digits = 0;
+            loop {
+                let buf = self.reader.fill_buf().unwrap();
+                let mut i = 0;
+                while i < buf.len() && buf[i].is_ascii_digit() {
+                    val = val.push_digit(buf[i] - b'0', negative);
+                    i += 1;
+                }
+                let ended = i < buf.len() || buf.is_empty();
+                self.reader.consume(i);
+                self.offset += i;
+                digits += i;
+                if ended {
                     break;
                 }
-                self.input_helper.push(c as char);
             }
-            self.input_helper.trim().to_string()
+
+            assert!(
+                digits > 0 && self.peek_byte().map_or(true, |b| b.is_ascii_whitespace()),
+                "Failed to parse input"
+            );
+            val
+        }
+    }
+
+    /// Implemented for tuples (up to length 6) of types implementing `FromStr`.
+    pub trait InputTuple: Sized {
+        fn input_from<S: ScannerOperations>(sc: &mut S) -> Self;
+    }
+    macro_rules! impl_input_tuple {
+        ($($t:ident),*) => {
+            impl<$($t: std::str::FromStr),*> InputTuple for ($($t,)*)
+            where
+                $($t::Err: std::fmt::Debug,)*
+            {
+                fn input_from<S: ScannerOperations>(sc: &mut S) -> Self {
+                    ($(sc.input::<$t>(),)*)
+                }
+            }
+        };
+    }
+    impl_input_tuple!(A);
+    impl_input_tuple!(A, B);
+    impl_input_tuple!(A, B, C);
+    impl_input_tuple!(A, B, C, D);
+    impl_input_tuple!(A, B, C, D, E);
+    impl_input_tuple!(A, B, C, D, E, F);
+
+    /// Integers which can be read using [ScannerOperations::input_int].
+    pub trait InputInteger: Copy {
+        const SIGNED: bool;
+
+        fn zero() -> Self;
+
+        /// Returns `self * 10 + d`, or `self * 10 - d` for negative numbers (so that `MIN` doesn't overflow).
+        ///
+        /// Panics if the number doesn't fit in the type (even in release, where the arithmetic would wrap).
+        fn push_digit(self, d: u8, negative: bool) -> Self;
+    }
+    macro_rules! impl_input_integer {
+        ($signed:expr; $($t:ty),*) => {
+            $(impl InputInteger for $t {
+                const SIGNED: bool = $signed;
+
+                #[inline]
+                fn zero() -> Self {
+                    0
+                }
+
+                #[inline]
+                fn push_digit(self, d: u8, negative: bool) -> Self {
+                    let val = self.checked_mul(10);
+                    if negative {
+                        val.and_then(|val| val.checked_sub(d as $t))
+                    } else {
+                        val.and_then(|val| val.checked_add(d as $t))
+                    }
+                    .expect("Failed to parse input")
+                }
+            })*
+        };
+    }
+    impl_input_integer!(false; u8, u16, u32, u64, u128, usize);
+    impl_input_integer!(true; i8, i16, i32, i64, i128, isize);
+
+    /// Buffered writer, similar to `FastWriter` in the Java template.
+    ///
+    /// The `print` methods format the integers by hand (check [Printable]), and the rest of the types implementing
+    /// `Display` can still be written using `write!` and `writeln!`.
+    ///
+    /// The buffer is flushed when the writer is dropped.
+    pub struct Writer<W: Write> {
+        out: W,
+        buf: Vec<u8>,
+        /// Flush after every new line, required in interactive problems.
+        auto_flush: bool,
+    }
+    impl<W: Write> Writer<W> {
+        pub fn new(out: W) -> Self {
+            Self {
+                out,
+                buf: Vec::with_capacity(IO_BUF_SIZE),
+                auto_flush: false,
+            }
+        }
+
+        /// Flushes after every new line, so that the judge receives every query as soon as it's printed.
+        pub fn interactive(out: W) -> Self {
+            let mut writer = Self::new(out);
+            writer.auto_flush = true;
+            writer
+        }
+
+        #[inline]
+        fn flush_if_full(&mut self) {
+            if self.buf.len() >= IO_BUF_SIZE {
+                self.out.write_all(&self.buf).unwrap();
+                self.buf.clear();
+            }
+        }
+
+        pub fn print<T: Printable>(&mut self, x: T) -> &mut Self {
+            x.print_to(&mut self.buf);
+            self.flush_if_full();
+            self
+        }
+
+        pub fn println<T: Printable>(&mut self, x: T) -> &mut Self {
+            x.print_to(&mut self.buf);
+            self.new_line()
+        }
+
+        pub fn new_line(&mut self) -> &mut Self {
+            self.buf.push(b'\n');
+            if self.auto_flush {
+                self.flush().unwrap();
+            } else {
+                self.flush_if_full();
+            }
+            self
+        }
+
+        /// Prints the elements separated by `sep`, followed by a new line (same as `printArray` in Java).
+        pub fn print_iter<I>(&mut self, iter: I, sep: &str) -> &mut Self
+        where
+            I: IntoIterator,
+            I::Item: Printable,
+        {
+            for (i, x) in iter.into_iter().enumerate() {
+                if i > 0 {
+                    self.buf.extend_from_slice(sep.as_bytes());
+                }
+                self.print(x);
+            }
+            self.new_line()
+        }
+
+        pub fn print_yes_no(&mut self, yes: bool) -> &mut Self {
+            self.println(if yes { "Yes" } else { "No" })
+        }
+
+        /// Prints the prefix `Case #k: `
+        pub fn print_case(&mut self, k: usize) -> &mut Self {
+            self.print("Case #").print(k).print(": ")
+        }
+    }
+    impl<W: Write> Write for Writer<W> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.buf.extend_from_slice(buf);
+            if self.auto_flush && buf.contains(&b'\n') {
+                self.flush()?;
+            } else {
+                self.flush_if_full();
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.out.write_all(&self.buf)?;
+            self.buf.clear();
+            self.out.flush()
+        }
+    }
+    impl<W: Write> Drop for Writer<W> {
+        fn drop(&mut self) {
+            // Same as BufWriter, ignoring the errors here
+            let _ = self.flush();
+        }
+    }
+    impl Writer<Vec<u8>> {
+        /// Flushes and takes everything written till now (used by the stress tests).
+        pub fn take_output(&mut self) -> Vec<u8> {
+            self.flush().unwrap();
+            std::mem::take(&mut self.out)
+        }
+    }
+
+    /// The types which can be printed using [Writer::print].
+    pub trait Printable {
+        fn print_to(&self, buf: &mut Vec<u8>);
+    }
+    macro_rules! impl_printable_for_unsigned_integers {
+        ($($t:ty),*) => {
+            $(impl Printable for $t {
+                fn print_to(&self, buf: &mut Vec<u8>) {
+                    // u128::MAX has 39 digits
+                    let mut digits = [0u8; 39];
+                    let mut i = digits.len();
+                    let mut x = *self;
+                    loop {
+                        i -= 1;
+                        digits[i] = b'0' + (x % 10) as u8;
+                        x /= 10;
+                        if x == 0 {
+                            break;
+                        }
+                    }
+                    buf.extend_from_slice(&digits[i..]);
+                }
+            })*
+        };
+    }
+    impl_printable_for_unsigned_integers!(u8, u16, u32, u64, u128, usize);
+    macro_rules! impl_printable_for_signed_integers {
+        ($($t:ty),*) => {
+            $(impl Printable for $t {
+                fn print_to(&self, buf: &mut Vec<u8>) {
+                    if *self < 0 {
+                        buf.push(b'-');
+                    }
+                    self.unsigned_abs().print_to(buf);
+                }
+            })*
+        };
+    }
+    impl_printable_for_signed_integers!(i8, i16, i32, i64, i128, isize);
+    macro_rules! impl_printable_using_display {
+        ($($t:ty),*) => {
+            $(impl Printable for $t {
+                fn print_to(&self, buf: &mut Vec<u8>) {
+                    write!(buf, "{}", self).unwrap();
+                }
+            })*
+        };
+    }
+    impl_printable_using_display!(f32, f64);
+    impl Printable for char {
+        fn print_to(&self, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(self.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+    impl Printable for str {
+        fn print_to(&self, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(self.as_bytes());
+        }
+    }
+    impl Printable for String {
+        fn print_to(&self, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(self.as_bytes());
+        }
+    }
+    impl<T: Printable + ?Sized> Printable for &T {
+        fn print_to(&self, buf: &mut Vec<u8>) {
+            (**self).print_to(buf);
+        }
+    }
+}
+/// Runs `solve` once for every test case (according to the chosen [TestCaseMode]), and prints the answers.
+///
+/// In the debug mode, every test case is timed, and a panic only stops the test case it occurred in.
+pub mod solution_driver {
+    use super::custom_io::{Printable, ScannerOperations, Writer};
+    use std::fmt::Display;
+    use std::io::Write;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::time::Instant;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub enum TestCaseMode {
+        Single,
+        /// The number of test cases is given at the start of the input.
+        Multiple,
+        /// Same as `Multiple`, but every answer starts with `Case #k: `.
+        CaseNumbered,
+        /// The test cases continue till the end of the input.
+        UntilEof,
+    }
+
+    /// The types which can be returned by `solve`, so that they're printed automatically.
+    ///
+    /// For other types implementing `Display` (like `ModInt`), return [Line]`(ans)`.
+    pub trait Answer {
+        fn print_answer<W: Write>(self, out: &mut Writer<W>);
+    }
+    /// `solve` prints the answer itself.
+    impl Answer for () {
+        fn print_answer<W: Write>(self, _out: &mut Writer<W>) {}
+    }
+    impl Answer for bool {
+        fn print_answer<W: Write>(self, out: &mut Writer<W>) {
+            out.print_yes_no(self);
+        }
+    }
+    /// Space separated, in a single line.
+    impl<T: Printable> Answer for Vec<T> {
+        fn print_answer<W: Write>(self, out: &mut Writer<W>) {
+            out.print_iter(self, " ");
+        }
+    }
+    macro_rules! impl_answer_for_printable_types {
+        ($($t:ty),*) => {
+            $(impl Answer for $t {
+                fn print_answer<W: Write>(self, out: &mut Writer<W>) {
+                    out.println(self);
+                }
+            })*
+        };
+    }
+    impl_answer_for_printable_types!(
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char, String,
+        &str
+    );
+
+    /// Any type implementing `Display`, printed in a line.
+    pub struct Line<T: Display>(pub T);
+    impl<T: Display> Answer for Line<T> {
+        fn print_answer<W: Write>(self, out: &mut Writer<W>) {
+            writeln!(out, "{}", self.0).unwrap();
+        }
+    }
+
+    pub trait SolutionDriver {
+        type Input: ScannerOperations;
+        type Output: Write;
+        type Answer: Answer;
+        const MODE: TestCaseMode;
+
+        fn io(&mut self) -> (&mut Self::Input, &mut Writer<Self::Output>);
+
+        fn solve(&mut self) -> Self::Answer;
+
+        fn run(&mut self) {
+            let testcases = match Self::MODE {
+                TestCaseMode::Single => Some(1),
+                TestCaseMode::Multiple | TestCaseMode::CaseNumbered => {
+                    Some(self.io().0.input_int::<usize>())
+                }
+                TestCaseMode::UntilEof => None,
+            };
+
+            let mut testcase = 1;
+            loop {
+                match testcases {
+                    Some(testcases) if testcase > testcases => break,
+                    None => {
+                        let sc = self.io().0;
+                        sc.skip_whitespaces();
+                        if sc.is_eof() {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+
+                if super::debug_mode() {
+                    if !self.debug_testcase(testcase) {
+                        // The rest of the test cases would fail with "Input not found!" as well.
+                        let sc = self.io().0;
+                        sc.skip_whitespaces();
+                        if sc.is_eof() {
+                            break;
+                        }
+                    }
+                } else {
+                    self.run_testcase(testcase);
+                }
+                testcase += 1;
+            }
+        }
+
+        fn run_testcase(&mut self, testcase: usize) {
+            if Self::MODE == TestCaseMode::CaseNumbered {
+                self.io().1.print_case(testcase);
+            }
+            let ans = self.solve();
+            ans.print_answer(self.io().1);
+        }
+
+        /// Times the test case and catches the panic (if any), returning whether it succeeded.
+        ///
+        /// The rest of the input of a failed test case is not skipped, so the next one might read it.
+        fn debug_testcase(&mut self, testcase: usize) -> bool {
+            let start = Instant::now();
+            let result = catch_unwind(AssertUnwindSafe(|| self.run_testcase(testcase)));
+            let elapsed = start.elapsed();
+            // So that the output of the test case is printed before its report
+            self.io().1.flush().unwrap();
+
+            match result {
+                Ok(()) => {
+                    eprintln!("Test case #{} took {:?}", testcase, elapsed);
+                    true
+                }
+                Err(e) => {
+                    let message = e
+                        .downcast_ref::<&str>()
+                        .map(|x| x.to_string())
+                        .or_else(|| e.downcast_ref::<String>().cloned())
+                        .unwrap_or_default();
+                    eprintln!(
+                        "Test case #{} panicked after {:?}: {}",
+                        testcase, elapsed, message
+                    );
+                    false
+                }
+            }
         }
     }
 }
-use custom_io::Scanner;
+use custom_io::{ScannerOperations, Writer};
+use solution_driver::{SolutionDriver, TestCaseMode};
 
-struct Solution<'io> {
-    sc: Scanner<'io>,
-    out: BufWriter<StdoutLock<'io>>,
+/// [custom_io::WholeInputScanner] is faster, but [custom_io::StreamScanner] uses less memory.
+///
+/// Use [custom_io::InteractiveScanner] for interactive problems (the output is flushed after every line then).
+type Scanner = custom_io::StreamScanner<std::io::BufReader<std::io::StdinLock<'static>>>;
+// type Scanner = custom_io::WholeInputScanner;
+// type Scanner = custom_io::InteractiveScanner<std::io::StdinLock<'static>>;
+
+struct Solution<S: ScannerOperations, W: Write> {
+    sc: S,
+    out: Writer<W>,
 }
-impl<'io> Solution<'io> {
-    pub fn with_io(sc: Scanner<'io>, out: BufWriter<StdoutLock<'io>>) -> Self {
+impl<S: ScannerOperations, W: Write> Solution<S, W> {
+    pub fn with_io(sc: S, out: Writer<W>) -> Self {
         Self { sc, out }
     }
 
-    pub fn solve(&mut self) {}
+    /// For interactive problems, prints the query and reads the response.
+    ///
+    /// `let res: i32 = self.query(format_args!("? {} {}", l, r));`
+    pub fn query<T: std::str::FromStr>(&mut self, query: std::fmt::Arguments) -> T
+    where
+        T::Err: std::fmt::Debug,
+    {
+        writeln!(self.out, "{}", query).unwrap();
+        self.out.flush().unwrap();
+        self.sc.input()
+    }
+}
+impl<S: ScannerOperations, W: Write> SolutionDriver for Solution<S, W> {
+    type Input = S;
+    type Output = W;
+    /// Printed automatically, check [solution_driver::Answer].
+    type Answer = ();
+    const MODE: TestCaseMode = TestCaseMode::Single;
+
+    fn io(&mut self) -> (&mut Self::Input, &mut Writer<Self::Output>) {
+        (&mut self.sc, &mut self.out)
+    }
+
+    fn solve(&mut self) -> Self::Answer {}
+}
+
+/// Runs the solution on `input`, and returns its output.
+fn run_in_memory(input: &str) -> String {
+    let sc = custom_io::WholeInputScanner::from_bytes(input.as_bytes().to_vec());
+    let mut sol = Solution::with_io(sc, Writer::new(Vec::new()));
+    sol.run();
+    String::from_utf8(sol.out.take_output()).unwrap()
+}
+
+/// Run with `-STRESS`, after pasting [stress.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/Template/stress.rs).
+fn stress_test() {
+    // let brute = |input: &str| -> String { todo!() };
+    // let result = stress(1, 1000, 10, |rng, size| todo!(), run_in_memory, brute);
+    // match result {
+    //     Ok(()) => eprintln!("All tests passed"),
+    //     Err(failure) => eprintln!("{:?}", failure),
+    // }
 }
@@ -0,0 +1,137 @@
+/// # Interactor ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/Template/interactor.rs))
+///
+/// A fake judge for testing interactive problems offline.
+///
+/// The solution (built from [Main.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/Template/Main.rs)
+/// with `InteractiveScanner`) runs as a child process, talking to the judge closure over pipes:
+///
+/// ```ignore
+/// let secret = 42;
+/// let interaction = interact(&mut Command::new("./main"), "100", |line| {
+///     let guess: i32 = line[2..].parse().unwrap();
+///     if line.starts_with("!") {
+///         if guess == secret { JudgeReply::Accept } else { JudgeReply::Reject(format!("{} is wrong", guess)) }
+///     } else {
+///         // Whether the secret is smaller than the guess
+///         JudgeReply::Reply((if secret < guess { "<" } else { ">=" }).to_string())
+///     }
+/// });
+/// assert!(interaction.accepted, "{:?}", interaction);
+/// ```
+pub mod interactor {
+    use std::fmt::{Debug, Formatter};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::process::{Command, Stdio};
+
+    pub enum JudgeReply {
+        /// Sends the line to the solution.
+        Reply(String),
+        /// Sends nothing, and waits for the next line of the solution.
+        Wait,
+        /// The interaction is over, and the solution is correct.
+        Accept,
+        /// The solution is wrong, with the reason.
+        Reject(String),
+    }
+
+    pub struct Interaction {
+        pub accepted: bool,
+        pub message: String,
+        /// The lines sent by the judge start with `> `, and the lines printed by the solution start with `< `.
+        pub transcript: Vec<String>,
+    }
+
+    impl Debug for Interaction {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{}: {}",
+                if self.accepted {
+                    "Accepted"
+                } else {
+                    "Rejected"
+                },
+                self.message
+            )?;
+            for line in &self.transcript {
+                writeln!(f)?;
+                write!(f, "{}", line)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Runs `cmd`, sends it `initial_input` (like `n` in most of the problems), and then calls `judge` on every line
+    /// printed by the solution, until the judge accepts or rejects it.
+    ///
+    /// The solution is also rejected if it exits before the verdict, prints anything after it, or fails.
+    pub fn interact<F>(cmd: &mut Command, initial_input: &str, mut judge: F) -> Interaction
+    where
+        F: FnMut(&str) -> JudgeReply,
+    {
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Failed to start the solution");
+        let mut to_solution = child.stdin.take().unwrap();
+        let mut from_solution = BufReader::new(child.stdout.take().unwrap());
+        let mut transcript = Vec::new();
+
+        let mut send = |line: &str, transcript: &mut Vec<String>| {
+            transcript.push(format!("> {}", line));
+            // The solution might have already exited, which is reported after this.
+            let _ = writeln!(to_solution, "{}", line).and_then(|_| to_solution.flush());
+        };
+        for line in initial_input.lines() {
+            send(line, &mut transcript);
+        }
+
+        let mut verdict = None;
+        let mut line = String::new();
+        while verdict.is_none() {
+            line.clear();
+            if from_solution.read_line(&mut line).unwrap() == 0 {
+                verdict = Some(Err("The solution exited before the verdict".to_string()));
+                break;
+            }
+            let query = line.trim_end();
+            transcript.push(format!("< {}", query));
+            match judge(query) {
+                JudgeReply::Reply(reply) => send(&reply, &mut transcript),
+                JudgeReply::Wait => {}
+                JudgeReply::Accept => verdict = Some(Ok("OK".to_string())),
+                JudgeReply::Reject(reason) => verdict = Some(Err(reason)),
+            }
+        }
+        let mut verdict = verdict.unwrap();
+
+        // The input ends here
+        drop(to_solution);
+        let mut rest = String::new();
+        from_solution.read_to_string(&mut rest).unwrap();
+        if verdict.is_ok() && !rest.trim().is_empty() {
+            verdict = Err("The solution printed something after the verdict".to_string());
+        }
+        transcript.extend(rest.lines().map(|x| format!("< {}", x)));
+
+        let status = child.wait().unwrap();
+        if verdict.is_ok() && !status.success() {
+            verdict = Err(format!("The solution failed: {}", status));
+        }
+
+        match verdict {
+            Ok(message) => Interaction {
+                accepted: true,
+                message,
+                transcript,
+            },
+            Err(message) => Interaction {
+                accepted: false,
+                message,
+                transcript,
+            },
+        }
+    }
+}
+use interactor::*;
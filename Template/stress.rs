@@ -0,0 +1,249 @@
+/// # Stress Testing ([source](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/Template/stress.rs))
+///
+/// Compares the solution with a brute force on random inputs, and reports the smallest failing input it finds.
+///
+/// In [Main.rs](https://github.com/SarthakMathur2182/CompetitiveProgramming/blob/main/Template/Main.rs), fill
+/// `stress_test()` and run with `-STRESS`. The solution is run in memory using `run_in_memory`:
+///
+/// ```ignore
+/// let result = stress(1, 1000, 10, |rng, size| {
+///     let n = rng.usize(1, size);
+///     format!("{}\n{}\n", n, rng.array(n, 1, 10).iter().map(|x| x.to_string()).collect::<Vec<_>>().join(" "))
+/// }, run_in_memory, brute);
+/// if let Err(failure) = result {
+///     eprintln!("{:?}", failure);
+/// }
+/// ```
+pub mod stress {
+    use std::collections::HashSet;
+    use std::fmt::{Debug, Formatter};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// A seeded random generator (SplitMix64), with the usual generators for the inputs.
+    ///
+    /// The ranges are inclusive, and the nodes of the trees and graphs are 0-based.
+    pub struct Random {
+        state: u64,
+    }
+
+    impl Random {
+        pub fn new(seed: u64) -> Self {
+            Self { state: seed }
+        }
+
+        pub fn next_u64(&mut self) -> u64 {
+            self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        pub fn int(&mut self, l: i64, r: i64) -> i64 {
+            assert!(l <= r, "Empty range [{}, {}]", l, r);
+            let len = (r as i128 - l as i128 + 1) as u128;
+            (l as i128 + (self.next_u64() as u128 % len) as i128) as i64
+        }
+
+        pub fn usize(&mut self, l: usize, r: usize) -> usize {
+            assert!(l <= r, "Empty range [{}, {}]", l, r);
+            l + (self.next_u64() % (r - l + 1) as u64) as usize
+        }
+
+        pub fn bool(&mut self) -> bool {
+            self.next_u64() & 1 == 1
+        }
+
+        pub fn array(&mut self, n: usize, l: i64, r: i64) -> Vec<i64> {
+            (0..n).map(|_| self.int(l, r)).collect()
+        }
+
+        pub fn shuffle<T>(&mut self, a: &mut [T]) {
+            for i in (1..a.len()).rev() {
+                a.swap(i, self.usize(0, i));
+            }
+        }
+
+        pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+            let mut p: Vec<usize> = (0..n).collect();
+            self.shuffle(&mut p);
+            p
+        }
+
+        /// The `n - 1` edges of a random tree, in a random order.
+        pub fn tree(&mut self, n: usize) -> Vec<(usize, usize)> {
+            let label = self.permutation(n);
+            let mut edges: Vec<(usize, usize)> = (1..n)
+                .map(|v| {
+                    let (u, v) = (label[self.usize(0, v - 1)], label[v]);
+                    if self.bool() {
+                        (u, v)
+                    } else {
+                        (v, u)
+                    }
+                })
+                .collect();
+            self.shuffle(&mut edges);
+            edges
+        }
+
+        /// The `m` edges of a random connected graph without self loops and multiple edges.
+        pub fn connected_graph(&mut self, n: usize, m: usize) -> Vec<(usize, usize)> {
+            assert!(
+                n > 0 && n - 1 <= m && m <= n * (n - 1) / 2,
+                "No simple connected graph has {} nodes and {} edges",
+                n,
+                m
+            );
+            let mut edges = self.tree(n);
+            let mut present: HashSet<(usize, usize)> =
+                edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+            while edges.len() < m {
+                let (u, v) = (self.usize(0, n - 1), self.usize(0, n - 1));
+                if u != v && present.insert((u.min(v), u.max(v))) {
+                    edges.push((u, v));
+                }
+            }
+            self.shuffle(&mut edges);
+            edges
+        }
+
+        /// A string of length `n`, with the characters taken from `alphabet`.
+        pub fn string(&mut self, n: usize, alphabet: &str) -> String {
+            let alphabet: Vec<char> = alphabet.chars().collect();
+            (0..n)
+                .map(|_| alphabet[self.usize(0, alphabet.len() - 1)])
+                .collect()
+        }
+
+        /// `m` clauses over `n` variables, in the format of `TwoSat::add_clause` (`a`, `a_negated`, `b`, `b_negated`).
+        pub fn two_sat(&mut self, n: usize, m: usize) -> Vec<(usize, bool, usize, bool)> {
+            (0..m)
+                .map(|_| {
+                    (
+                        self.usize(0, n - 1),
+                        self.bool(),
+                        self.usize(0, n - 1),
+                        self.bool(),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    pub struct StressFailure {
+        /// The `size` passed to the generator for this input.
+        pub size: usize,
+        pub input: String,
+        /// The output of the brute force.
+        pub expected: String,
+        /// The output of the solution, or the panic message.
+        pub found: String,
+    }
+
+    impl Debug for StressFailure {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "Failed on the input (size {}):", self.size)?;
+            writeln!(f, "{}", self.input.trim_end())?;
+            writeln!(f, "Expected:")?;
+            writeln!(f, "{}", self.expected.trim_end())?;
+            writeln!(f, "Found:")?;
+            write!(f, "{}", self.found.trim_end())
+        }
+    }
+
+    /// Runs `iterations` random tests, with the `size` passed to `generate` growing from `1` to `max_size`.
+    ///
+    /// The outputs are compared token by token, and a panic in the solution counts as a failure. After the first
+    /// failure, the rest of the iterations generate the inputs with smaller sizes, and the shortest
+    /// failing input is returned.
+    ///
+    /// The panic hook is silenced while running, so that the failing tests don't flood stderr.
+    pub fn stress<G, S, B>(
+        seed: u64,
+        iterations: usize,
+        max_size: usize,
+        mut generate: G,
+        mut solution: S,
+        mut brute: B,
+    ) -> Result<(), StressFailure>
+    where
+        G: FnMut(&mut Random, usize) -> String,
+        S: FnMut(&str) -> String,
+        B: FnMut(&str) -> String,
+    {
+        let mut rng = Random::new(seed);
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+
+        let mut failure: Option<StressFailure> = None;
+        let mut remaining = iterations;
+        for i in 0..iterations {
+            let size = 1 + i * max_size / iterations.max(1);
+            let input = generate(&mut rng, size);
+            if let Some(f) = check(&input, size, &mut solution, &mut brute) {
+                failure = Some(f);
+                remaining = iterations - i;
+                break;
+            }
+        }
+
+        if let Some(best) = failure.as_mut() {
+            for _ in 0..remaining {
+                if best.size == 1 {
+                    break;
+                }
+                let size = rng.usize(1, best.size);
+                let input = generate(&mut rng, size);
+                if input.len() >= best.input.len() {
+                    continue;
+                }
+                if let Some(f) = check(&input, size, &mut solution, &mut brute) {
+                    *best = f;
+                }
+            }
+        }
+
+        std::panic::set_hook(hook);
+        match failure {
+            Some(failure) => Err(failure),
+            None => Ok(()),
+        }
+    }
+
+    fn check<S, B>(
+        input: &str,
+        size: usize,
+        solution: &mut S,
+        brute: &mut B,
+    ) -> Option<StressFailure>
+    where
+        S: FnMut(&str) -> String,
+        B: FnMut(&str) -> String,
+    {
+        let expected = brute(input);
+        let found = match catch_unwind(AssertUnwindSafe(|| solution(input))) {
+            Ok(found) => found,
+            Err(e) => {
+                let message = e
+                    .downcast_ref::<&str>()
+                    .map(|x| x.to_string())
+                    .or_else(|| e.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                format!("Panicked: {}", message)
+            }
+        };
+
+        if expected.split_whitespace().eq(found.split_whitespace()) {
+            None
+        } else {
+            Some(StressFailure {
+                size,
+                input: input.to_string(),
+                expected,
+                found,
+            })
+        }
+    }
+}
+use stress::*;
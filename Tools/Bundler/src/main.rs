@@ -0,0 +1,807 @@
+//! # Bundler
+//!
+//! Every library file is a single `pub mod name { ... }` (followed by its `use name::*;`), and the modules reach
+//! into each other through `super::` (e.g. `seg_tree` needs `get_inclusive_usize_bounds` from `my_utils`). This
+//! binary inlines everything a solution needs into one submission file:
+//!
+//! `cargo run --release --manifest-path Tools/Bundler/Cargo.toml -- Template/Main.rs -o submission.rs`
+//!
+//! - A library module is needed if the solution uses its name as a path (`use seg_tree::*`, `bs::first_true(..)`) or
+//!   mentions anything it exports (`SegmentTree`, `gcd`, ...), ignoring method calls (`.first_true(..)`) and the names
+//!   the solution defines itself. So a variable `tree` doesn't pull in the `tree` module.
+//! - The dependencies are resolved through the `super::` paths (and `use super::*`) of the needed modules.
+//! - Library modules already pasted into the solution are kept as they are if needed, and removed otherwise.
+//! - The missing modules are inserted (dependencies first) right after the last top-level module of the solution.
+//! - The public items declared by a `macro_rules!` can't be seen, so the modules doing it are warned about.
+//!
+//! Only std is used, so the source is scanned with a small lexer instead of a real parser.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+/// Directories (relative to the library root) which do not contain library modules.
+const SKIPPED_DIRS: [&str; 6] = ["Template", "Tools", "target", "benches", "tests", "src"];
+
+/// The keywords after which the next identifier is the name of a new item.
+const ITEM_KEYWORDS: [&str; 8] = [
+    "fn", "struct", "enum", "trait", "type", "const", "static", "mod",
+];
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut solution = None;
+    let mut output = None;
+    let mut lib_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(args.next().unwrap_or_else(|| usage())),
+            "--lib" => lib_root = PathBuf::from(args.next().unwrap_or_else(|| usage())),
+            "-h" | "--help" => usage(),
+            _ if solution.is_none() => solution = Some(arg),
+            _ => usage(),
+        }
+    }
+    let solution = solution.unwrap_or_else(|| usage());
+
+    let library = Library::load(&lib_root);
+    for warning in library.warnings() {
+        eprintln!("{}", warning);
+    }
+    let source = std::fs::read_to_string(&solution)
+        .unwrap_or_else(|e| fail(&format!("Cannot read {}: {}", solution, e)));
+    let bundle = library.bundle(&source);
+
+    for name in &bundle.inlined {
+        eprintln!("Inlined {}", name);
+    }
+    for name in &bundle.removed {
+        eprintln!("Removed unused {}", name);
+    }
+    match output {
+        Some(path) => std::fs::write(&path, bundle.source)
+            .unwrap_or_else(|e| fail(&format!("Cannot write {}: {}", path, e))),
+        None => print!("{}", bundle.source),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: bundler <solution.rs> [-o <output.rs>] [--lib <library root>]");
+    exit(2);
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    exit(1);
+}
+
+/// A token of the masked source, with its byte range in the original source.
+#[derive(Debug, Clone)]
+struct Token {
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn is(&self, text: &str) -> bool {
+        self.text == text
+    }
+
+    fn is_ident(&self) -> bool {
+        self.text
+            .bytes()
+            .next()
+            .is_some_and(|b| b == b'_' || b.is_ascii_alphabetic())
+    }
+}
+
+/// Replaces the comments and the contents of string and char literals by spaces (keeping the newlines), so that the
+/// byte offsets stay the same and the braces and identifiers inside them are ignored.
+fn mask(src: &str) -> Vec<u8> {
+    let s = src.as_bytes();
+    let mut masked = s.to_vec();
+    let blank = |masked: &mut Vec<u8>, l: usize, r: usize| {
+        for b in &mut masked[l..r] {
+            if *b != b'\n' {
+                *b = b' ';
+            }
+        }
+    };
+    let is_ident_byte = |b: u8| b == b'_' || b.is_ascii_alphanumeric();
+
+    let mut i = 0;
+    while i < s.len() {
+        let prev_ident = i > 0 && is_ident_byte(s[i - 1]);
+        // The `r` of a raw byte string `br"..."` follows the `b`
+        let byte_prefix = i > 0 && s[i - 1] == b'b' && (i < 2 || !is_ident_byte(s[i - 2]));
+        if s[i..].starts_with(b"//") {
+            let end = s[i..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(s.len(), |p| i + p);
+            blank(&mut masked, i, end);
+            i = end;
+        } else if s[i..].starts_with(b"/*") {
+            let (mut depth, mut j) = (0, i);
+            while j < s.len() {
+                if s[j..].starts_with(b"/*") {
+                    depth += 1;
+                    j += 2;
+                } else if s[j..].starts_with(b"*/") {
+                    depth -= 1;
+                    j += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    j += 1;
+                }
+            }
+            blank(&mut masked, i, j);
+            i = j;
+        } else if s[i] == b'r'
+            && (!prev_ident || byte_prefix)
+            && matches!(s.get(i + 1), Some(b'"' | b'#'))
+        {
+            let hashes = s[i + 1..].iter().take_while(|&&b| b == b'#').count();
+            if s.get(i + 1 + hashes) != Some(&b'"') {
+                i += 1;
+                continue;
+            }
+            let mut closing = vec![b'#'; hashes + 1];
+            closing[0] = b'"';
+            let body = i + 2 + hashes;
+            let end = (body..s.len())
+                .find(|&j| s[j..].starts_with(&closing))
+                .map_or(s.len(), |j| j + closing.len());
+            blank(&mut masked, i, end);
+            i = end;
+        } else if s[i] == b'"' {
+            let mut j = i + 1;
+            while j < s.len() && s[j] != b'"' {
+                j += if s[j] == b'\\' { 2 } else { 1 };
+            }
+            let end = (j + 1).min(s.len());
+            blank(&mut masked, i, end);
+            i = end;
+        } else if s[i] == b'\'' {
+            // A char literal is either an escape or a single char followed by a quote, anything else is a lifetime.
+            let end = if s.get(i + 1) == Some(&b'\\') {
+                (i + 2..s.len()).find(|&j| s[j] == b'\'').map(|j| j + 1)
+            } else {
+                src[i + 1..]
+                    .chars()
+                    .next()
+                    .map(|c| i + 1 + c.len_utf8())
+                    .filter(|&j| s.get(j) == Some(&b'\''))
+                    .map(|j| j + 1)
+            };
+            match end {
+                Some(end) => {
+                    blank(&mut masked, i, end);
+                    i = end;
+                }
+                None => i += 1,
+            }
+        } else {
+            i += 1;
+        }
+    }
+    masked
+}
+
+/// Splits the masked source into identifiers (and numbers) and single punctuation characters.
+fn tokenize(masked: &[u8]) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < masked.len() {
+        let b = masked[i];
+        if b.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        let end = if b == b'_' || b.is_ascii_alphanumeric() {
+            i + masked[i..]
+                .iter()
+                .take_while(|&&b| b == b'_' || b.is_ascii_alphanumeric())
+                .count()
+        } else {
+            i + 1
+        };
+        tokens.push(Token {
+            text: String::from_utf8_lossy(&masked[i..end]).into_owned(),
+            start: i,
+            end,
+        });
+        i = end;
+    }
+    tokens
+}
+
+/// The index of the `}` matching the `{` at `tokens[open]`.
+fn matching_brace(tokens: &[Token], open: usize) -> usize {
+    let mut depth = 0;
+    for (i, t) in tokens.iter().enumerate().skip(open) {
+        if t.is("{") {
+            depth += 1;
+        } else if t.is("}") {
+            depth -= 1;
+            if depth == 0 {
+                return i;
+            }
+        }
+    }
+    tokens.len() - 1
+}
+
+/// The top-level modules, as `(name, index of "mod", index of "{", index of "}")`.
+fn top_level_modules(tokens: &[Token]) -> Vec<(String, usize, usize, usize)> {
+    let mut modules = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        if tokens[i].is("{") {
+            i = matching_brace(tokens, i) + 1;
+            continue;
+        }
+        if tokens[i].is("mod") && i + 2 < tokens.len() && tokens[i + 2].is("{") {
+            let close = matching_brace(tokens, i + 2);
+            modules.push((tokens[i + 1].text.clone(), i, i + 2, close));
+            i = close + 1;
+            continue;
+        }
+        i += 1;
+    }
+    modules
+}
+
+/// The name under which `tokens[i]` is recorded as a reference: `name::` if it starts a path (which is the only way to
+/// refer to a module), and `name` otherwise.
+fn reference_name(tokens: &[Token], i: usize) -> String {
+    let is_path = tokens.get(i + 1).is_some_and(|t| t.is(":"))
+        && tokens.get(i + 2).is_some_and(|t| t.is(":"));
+    if is_path {
+        format!("{}::", tokens[i].text)
+    } else {
+        tokens[i].text.clone()
+    }
+}
+
+/// The references (check `reference_name`) of the identifiers which can refer to a library item, i.e. the ones that
+/// are not method calls or field accesses, except the ones in `defined`.
+fn free_references(tokens: &[Token], defined: &HashSet<&str>) -> HashSet<String> {
+    (0..tokens.len())
+        .filter(|&i| tokens[i].is_ident() && (i == 0 || !tokens[i - 1].is(".")))
+        .filter(|&i| !defined.contains(tokens[i].text.as_str()))
+        .map(|i| reference_name(tokens, i))
+        .collect()
+}
+
+/// A library file, containing a single top-level module.
+struct Module {
+    name: String,
+    path: PathBuf,
+    source: String,
+    /// The public items reachable from the crate root through modules only (i.e. not methods or associated items).
+    exports: HashSet<String>,
+    /// The identifiers this module might use from the other modules (through `super::`), as in `reference_name`.
+    references: HashSet<String>,
+    /// The `macro_rules!` declaring public items (like `pub struct $name`), which can't be found in `exports`.
+    macros_with_items: Vec<String>,
+}
+
+impl Module {
+    fn parse(path: PathBuf, source: String) -> Option<Self> {
+        let tokens = tokenize(&mask(&source));
+        let modules = top_level_modules(&tokens);
+        if modules.len() != 1 {
+            return None;
+        }
+        let (name, mod_token, _, close) = modules[0].clone();
+        let body = &tokens[mod_token..=close];
+
+        let mut exports = HashSet::new();
+        let mut in_modules = vec![];
+        for (i, t) in body.iter().enumerate() {
+            if t.is("{") {
+                in_modules.push(i >= 2 && body[i - 2].is("mod"));
+            } else if t.is("}") {
+                in_modules.pop();
+            } else if t.is("pub") && in_modules.iter().all(|&m| m) {
+                let mut j = i + 1;
+                while j < body.len()
+                    && matches!(body[j].text.as_str(), "const" | "unsafe" | "extern")
+                    && body
+                        .get(j + 1)
+                        .is_some_and(|t| t.is("fn") || t.is("unsafe"))
+                {
+                    j += 1;
+                }
+                if body
+                    .get(j)
+                    .is_some_and(|t| ITEM_KEYWORDS.contains(&t.text.as_str()))
+                {
+                    if let Some(item) = body.get(j + 1).filter(|t| t.is_ident()) {
+                        exports.insert(item.text.clone());
+                    }
+                }
+            }
+        }
+
+        let mut macros_with_items = vec![];
+        for i in 0..body.len() {
+            if !(body[i].is("macro_rules") && body.get(i + 1).is_some_and(|t| t.is("!"))) {
+                continue;
+            }
+            if !body.get(i + 3).is_some_and(|t| t.is("{")) {
+                continue;
+            }
+            let close = i + 3 + matching_brace(&body[i + 3..], 0);
+            let declares_items = body[i + 3..close].windows(3).any(|w| {
+                w[0].is("pub") && ITEM_KEYWORDS.contains(&w[1].text.as_str()) && w[2].is("$")
+            });
+            if declares_items {
+                macros_with_items.push(body[i + 2].text.clone());
+            }
+        }
+
+        let mut references = HashSet::new();
+        let mut glob = false;
+        for i in 0..body.len() {
+            if !(body[i].is("super") && body.get(i + 1).is_some_and(|t| t.is(":"))) {
+                continue;
+            }
+            match body.get(i + 3) {
+                Some(t) if t.is("*") => glob = true,
+                Some(t) if t.is("{") => {
+                    let close = i + 3 + matching_brace(&body[i + 3..], 0);
+                    let mut depth = 0;
+                    for j in i + 3..close {
+                        match body[j].text.as_str() {
+                            "{" => depth += 1,
+                            "}" => depth -= 1,
+                            "*" if depth == 1 => glob = true,
+                            _ if depth == 1
+                                && body[j].is_ident()
+                                && (body[j - 1].is("{") || body[j - 1].is(",")) =>
+                            {
+                                references.insert(reference_name(body, j));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Some(t) if t.is_ident() => {
+                    references.insert(reference_name(body, i + 3));
+                }
+                _ => {}
+            }
+        }
+        if glob {
+            references.extend(free_references(body, &HashSet::new()));
+        }
+        references.remove(&name);
+        references.remove(&format!("{}::", name));
+
+        Some(Self {
+            name,
+            path,
+            source,
+            exports,
+            references,
+            macros_with_items,
+        })
+    }
+
+    /// Whether any of the `references` (as in `reference_name`) refer to this module or to one of its exports.
+    fn is_referred_by<'a>(&self, mut references: impl Iterator<Item = &'a String>) -> bool {
+        references.any(|x| match x.strip_suffix("::") {
+            Some(path) => path == self.name || self.exports.contains(path),
+            None => self.exports.contains(x),
+        })
+    }
+}
+
+struct Library {
+    modules: Vec<Module>,
+    /// `dependencies[i]` are the indices of the modules that the `i`-th module refers to.
+    dependencies: Vec<Vec<usize>>,
+}
+
+struct Bundle {
+    source: String,
+    inlined: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl Library {
+    fn load(root: &Path) -> Self {
+        let mut files = vec![];
+        collect_files(root, root, &mut files);
+        files.sort();
+
+        let mut modules: Vec<Module> = vec![];
+        for path in files {
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Some(module) = Module::parse(path, source) {
+                if let Some(other) = modules.iter().find(|m| m.name == module.name) {
+                    fail(&format!(
+                        "Module {} is defined in both {} and {}",
+                        module.name,
+                        other.path.display(),
+                        module.path.display()
+                    ));
+                }
+                modules.push(module);
+            }
+        }
+        Self::new(modules)
+    }
+
+    fn new(modules: Vec<Module>) -> Self {
+        let dependencies = modules
+            .iter()
+            .map(|m| {
+                (0..modules.len())
+                    .filter(|&j| {
+                        modules[j].name != m.name && modules[j].is_referred_by(m.references.iter())
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            modules,
+            dependencies,
+        }
+    }
+
+    /// The modules whose public items can't all be found, as they're declared by their macros.
+    fn warnings(&self) -> Vec<String> {
+        self.modules
+            .iter()
+            .filter(|m| !m.macros_with_items.is_empty())
+            .map(|m| {
+                format!(
+                    "Warning: {} declares public items inside macro_rules! {}, so a solution using only them \
+                     won't get the module inlined. Declare them outside the macros.",
+                    m.path.display(),
+                    m.macros_with_items.join(", ")
+                )
+            })
+            .collect()
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.modules.iter().position(|m| m.name == name)
+    }
+
+    fn bundle(&self, source: &str) -> Bundle {
+        let tokens = tokenize(&mask(source));
+
+        // The library modules which are already present, with the byte ranges which would remove them.
+        let mut pasted: HashMap<usize, (usize, usize)> = HashMap::new();
+        for (name, mod_token, _, close) in top_level_modules(&tokens) {
+            let Some(id) = self.find(&name) else {
+                continue;
+            };
+            let span = self.pasted_span(id, source, &tokens, mod_token, close);
+            pasted.insert(id, span);
+        }
+        let outside_pasted = |t: &Token| pasted.values().all(|&(l, r)| t.end <= l || t.start >= r);
+
+        let solution_tokens: Vec<Token> = tokens
+            .iter()
+            .filter(|t| outside_pasted(t))
+            .cloned()
+            .collect();
+        let defined: HashSet<&str> = solution_tokens
+            .windows(2)
+            .filter(|w| ITEM_KEYWORDS.contains(&w[0].text.as_str()) && w[1].is_ident())
+            .map(|w| w[1].text.as_str())
+            .collect();
+        let used = free_references(&solution_tokens, &defined);
+
+        // Dependencies first, and in the order of the files otherwise.
+        let mut order = vec![];
+        let mut visited = vec![false; self.modules.len()];
+        for id in 0..self.modules.len() {
+            if self.modules[id].is_referred_by(used.iter()) {
+                self.visit(id, &mut visited, &mut order);
+            }
+        }
+
+        let mut removed_spans: Vec<(usize, usize)> = vec![];
+        let mut removed = vec![];
+        for (&id, &span) in &pasted {
+            if !visited[id] {
+                removed_spans.push(span);
+                removed.push(self.modules[id].name.clone());
+            }
+        }
+        removed_spans.sort();
+        removed.sort();
+        let mut cleaned = String::new();
+        let mut last = 0;
+        for (l, r) in removed_spans {
+            cleaned.push_str(&source[last..l]);
+            last = r;
+        }
+        cleaned.push_str(&source[last..]);
+
+        let missing: Vec<usize> = order
+            .into_iter()
+            .filter(|id| !pasted.contains_key(id))
+            .collect();
+        let mut inserted = String::new();
+        for &id in &missing {
+            inserted.push('\n');
+            inserted.push_str(self.modules[id].source.trim_end());
+            inserted.push('\n');
+        }
+
+        let at = insertion_point(&cleaned);
+        let mut bundled = cleaned[..at].to_string();
+        if !bundled.is_empty() && !bundled.ends_with('\n') {
+            bundled.push('\n');
+        }
+        bundled.push_str(&inserted);
+        bundled.push_str(&cleaned[at..]);
+
+        Bundle {
+            source: bundled,
+            inlined: missing
+                .into_iter()
+                .map(|id| self.modules[id].name.clone())
+                .collect(),
+            removed,
+        }
+    }
+
+    fn visit(&self, id: usize, visited: &mut Vec<bool>, order: &mut Vec<usize>) {
+        if visited[id] {
+            return;
+        }
+        visited[id] = true;
+        for &dep in &self.dependencies[id] {
+            self.visit(dep, visited, order);
+        }
+        order.push(id);
+    }
+
+    /// The byte range to remove in order to drop a pasted module: the whole library file if it was pasted as it is,
+    /// otherwise the module along with the comments and attributes above it and its `use` right after it.
+    fn pasted_span(
+        &self,
+        id: usize,
+        source: &str,
+        tokens: &[Token],
+        mod_token: usize,
+        close: usize,
+    ) -> (usize, usize) {
+        let library_source = self.modules[id].source.trim_end();
+        if let Some(l) = source.find(library_source) {
+            return (l, line_end(source, l + library_source.len()));
+        }
+
+        let mut l = tokens[mod_token].start;
+        if mod_token > 0 && tokens[mod_token - 1].is("pub") {
+            l = tokens[mod_token - 1].start;
+        }
+        l = source[..l].rfind('\n').map_or(0, |p| p + 1);
+        while l > 0 {
+            let prev = source[..l - 1].rfind('\n').map_or(0, |p| p + 1);
+            let line = source[prev..l].trim_start();
+            if !(line.starts_with("///") || line.starts_with("#[")) {
+                break;
+            }
+            l = prev;
+        }
+
+        let mut r = close;
+        let name = &self.modules[id].name;
+        if tokens.get(r + 1).is_some_and(|t| t.is("use"))
+            && tokens.get(r + 2).is_some_and(|t| t.is(name))
+        {
+            r += 1;
+            while r + 1 < tokens.len() && !tokens[r].is(";") {
+                r += 1;
+            }
+        }
+        (l, line_end(source, tokens[r].end))
+    }
+}
+
+/// The position right after the newline ending the line containing `pos`.
+fn line_end(source: &str, pos: usize) -> usize {
+    source[pos..]
+        .find('\n')
+        .map_or(source.len(), |p| pos + p + 1)
+}
+
+/// Right after the last top-level module, so that the library modules end up next to the template's modules.
+fn insertion_point(source: &str) -> usize {
+    let tokens = tokenize(&mask(source));
+    match top_level_modules(&tokens).last() {
+        Some(&(_, _, _, close)) => line_end(source, tokens[close].end),
+        None => source.len(),
+    }
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if path.is_dir() {
+            let skipped =
+                name.starts_with('.') || (dir == root && SKIPPED_DIRS.contains(&name.as_str()));
+            if !skipped {
+                collect_files(root, &path, files);
+            }
+        } else if name.ends_with(".rs") {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The identifiers left after masking `src`.
+    fn identifiers(src: &str) -> Vec<String> {
+        tokenize(&mask(src))
+            .into_iter()
+            .filter(|t| t.is_ident())
+            .map(|t| t.text)
+            .collect()
+    }
+
+    fn library(files: &[&str]) -> Library {
+        Library::new(
+            files
+                .iter()
+                .enumerate()
+                .map(|(i, src)| {
+                    Module::parse(PathBuf::from(i.to_string()), src.to_string()).unwrap()
+                })
+                .collect(),
+        )
+    }
+
+    /// Bundles `solution` with the library of this repo, then compiles and runs it, returning its output.
+    fn bundle_and_run(name: &str, solution: &str) -> (Bundle, String) {
+        let library = Library::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."));
+        let bundle = library.bundle(solution);
+        let dir = std::env::temp_dir().join(format!("bundler-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("main.rs"), &bundle.source).unwrap();
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let compiled = std::process::Command::new(rustc)
+            .args(["--edition", "2021", "-A", "warnings", "-o"])
+            .arg(dir.join("main"))
+            .arg(dir.join("main.rs"))
+            .output()
+            .unwrap();
+        assert!(
+            compiled.status.success(),
+            "The bundle doesn't compile:\n{}",
+            String::from_utf8_lossy(&compiled.stderr)
+        );
+        let run = std::process::Command::new(dir.join("main"))
+            .output()
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        (bundle, String::from_utf8(run.stdout).unwrap())
+    }
+
+    #[test]
+    fn masks_literals_and_comments() {
+        let src = r####"a r#"b "c" { d"# e br##"f "# g"## h b"i \" {" j"####;
+        // Only the `b` prefixes of the byte strings are left
+        assert_eq!(identifiers(src), ["a", "e", "b", "h", "b", "j"]);
+        assert_eq!(mask(src).len(), src.len());
+
+        let src = "/* a /* nested { */ still */ b // c {\n d";
+        assert_eq!(identifiers(src), ["b", "d"]);
+        assert_eq!(mask(src).iter().filter(|&&b| b == b'\n').count(), 1);
+    }
+
+    #[test]
+    fn masks_chars_but_not_lifetimes() {
+        let src = r"fn f<'a>(x: &'a str) { ('{', '\'', '\u{7b}', 'é', b'x') }";
+        assert_eq!(identifiers(src), ["fn", "f", "a", "x", "a", "str", "b"]);
+        let tokens = tokenize(&mask(src));
+        let braces = tokens.iter().filter(|t| t.is("{") || t.is("}")).count();
+        assert_eq!(braces, 2);
+    }
+
+    #[test]
+    fn resolves_transitive_dependencies() {
+        let library = library(&[
+            "pub mod a {\n    pub struct A;\n    fn f() { super::b_fn(); }\n}\nuse a::*;\n",
+            "pub mod b {\n    use super::C;\n    pub fn b_fn() {}\n}\nuse b::*;\n",
+            "pub mod c {\n    pub struct C;\n}\nuse c::*;\n",
+            "pub mod unrelated {\n    pub fn other() {}\n}\n",
+        ]);
+        let bundle = library.bundle("mod solution {\n    fn main() { let x = A; }\n}\n");
+        assert_eq!(bundle.inlined, ["c", "b", "a"]);
+        assert!(bundle.removed.is_empty());
+        let position = |m: &str| bundle.source.find(m).unwrap();
+        assert!(bundle.source.starts_with("mod solution"));
+        assert!(position("pub mod c") < position("pub mod b"));
+        assert!(position("pub mod b") < position("pub mod a"));
+    }
+
+    #[test]
+    fn removes_unused_and_keeps_pasted_modules() {
+        let used = "pub mod used {\n    pub fn f() {}\n}\nuse used::*;\n";
+        let unused = "pub mod unused {\n    pub fn g() {}\n}\nuse unused::*;\n";
+        let library = library(&[used, unused]);
+        let source = format!("fn main() {{ f(); }}\n{}{}", used, unused);
+        let bundle = library.bundle(&source);
+        assert!(bundle.inlined.is_empty());
+        assert_eq!(bundle.removed, ["unused"]);
+        assert_eq!(bundle.source, format!("fn main() {{ f(); }}\n{}", used));
+    }
+
+    #[test]
+    fn module_names_count_only_as_paths() {
+        let library = library(&["pub mod tree {\n    pub struct Tree;\n}\nuse tree::*;\n"]);
+        let bundle = library.bundle("fn main() { let tree = 5; println!(\"{}\", tree); }\n");
+        assert!(bundle.inlined.is_empty());
+        let bundle = library.bundle("fn main() { let t = tree::Tree; }\n");
+        assert_eq!(bundle.inlined, ["tree"]);
+    }
+
+    #[test]
+    fn bundled_seg_tree_ops_compile() {
+        let solution = "fn main() {\n    \
+            let tree = SegmentTree::<Sum<i64>>::with_nodes(&vec![1, 2, 3]);\n    \
+            println!(\"{}\", tree.query(..));\n}\n";
+        let (bundle, output) = bundle_and_run("seg_tree_ops", solution);
+        assert!(bundle.inlined.iter().any(|m| m == "seg_tree_ops"));
+        assert_eq!(output, "6\n");
+    }
+
+    #[test]
+    fn warns_about_items_declared_by_macros() {
+        let library = library(&[
+            "pub mod m {\n    macro_rules! declare {\n        ($name:ident) => {\n            \
+             pub struct $name;\n        };\n    }\n    declare!(Hidden);\n    pub struct Seen;\n}\n",
+            "pub mod n {\n    macro_rules! implement {\n        ($t:ty) => {\n            \
+             impl Tr for $t {}\n        };\n    }\n}\n",
+        ]);
+        assert_eq!(library.modules[0].macros_with_items, ["declare"]);
+        assert!(library.modules[0].exports.contains("Seen"));
+        assert!(library.modules[1].macros_with_items.is_empty());
+        let warnings = library.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("macro_rules! declare"));
+    }
+
+    #[test]
+    fn library_has_no_items_declared_by_macros() {
+        let library = Library::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."));
+        assert_eq!(library.warnings(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn bundled_lazy_seg_tree_ops_run() {
+        let solution = "use std::io::Write;\n\
+            fn main() {\n    \
+            let mut max = LazySegmentTree::<AddMax<i64>>::with_nodes(vec![3, 1, 4, 1, 5]);\n    \
+            max.update(1..=3, 10);\n    \
+            let mut min = IterativeLazySegmentTree::<AssignMin<i32>>::with_nodes(vec![3, 1, 4]);\n    \
+            min.update(..2, Some(7));\n    \
+            writeln!(std::io::stdout(), \"{} {}\", max.query(..), min.query(..)).unwrap();\n}\n";
+        let (bundle, output) = bundle_and_run("lazy_seg_tree_ops", solution);
+        let position = |m: &str| bundle.inlined.iter().position(|x| x == m).unwrap();
+        assert!(position("seg_tree") < position("seg_tree_ops"));
+        assert_eq!(output, "14 4\n");
+    }
+}
@@ -0,0 +1,524 @@
+//! # Sample Runner
+//!
+//! Runs a solution (built from `Template/Main.rs`) on every `*.in` file of a directory, and compares its output with
+//! the `*.out` file of the same name:
+//!
+//! `cargo run --release --manifest-path Tools/SampleRunner/Cargo.toml -- Template/Main.rs samples/`
+//!
+//! - The solution can be a binary, or a `.rs` file which is compiled first (with `rustc -O`).
+//! - The solution is run with `-DEBUG`, so a single `*.in` file can contain several samples one after the other.
+//!   The template then prints `Next Input:` between their outputs, and the `*.out` file can separate the expected
+//!   outputs the same way (otherwise the outputs are compared together).
+//! - The outputs are compared token by token, and with `--eps` the numbers are compared with an absolute or relative
+//!   error of `eps`.
+//! - A panic is caught by the template in the debug mode, so it's detected from the report on stderr.
+//!
+//! The exit code is 1 if any sample fails, so it can be used in scripts.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Printed by the template between the outputs of the concatenated inputs in the debug mode.
+const SEPARATOR: &str = "Next Input:";
+
+/// The number of lines shown around the first difference.
+const DIFF_CONTEXT: usize = 5;
+
+#[derive(Debug, Copy, Clone)]
+enum Checker {
+    Tokens,
+    /// The numbers can have an absolute or relative error of `eps`.
+    Float(f64),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Verdict {
+    Passed,
+    Failed,
+    /// The expected output is missing, so the sample wasn't run.
+    Skipped,
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut paths = vec![];
+    let mut checker = Checker::Tokens;
+    let mut timeout = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--eps" => {
+                let eps = args.next().unwrap_or_else(|| usage());
+                checker = Checker::Float(eps.parse().unwrap_or_else(|_| usage()));
+            }
+            "--timeout" => {
+                let secs: f64 = args
+                    .next()
+                    .and_then(|x| x.parse().ok())
+                    .unwrap_or_else(|| usage());
+                timeout = Some(Duration::from_secs_f64(secs));
+            }
+            "-h" | "--help" => usage(),
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    if paths.len() != 2 {
+        usage();
+    }
+    let (solution, dir) = (&paths[0], &paths[1]);
+
+    let binary = if solution.extension().is_some_and(|ext| ext == "rs") {
+        compile(solution)
+    } else {
+        solution.clone()
+    };
+
+    let samples = collect_samples(dir);
+    if samples.is_empty() {
+        fail(&format!("No *.in files found in {}", dir.display()));
+    }
+
+    let verdicts: Vec<Verdict> = samples
+        .iter()
+        .map(|(input, output)| {
+            let name = input.file_name().unwrap().to_string_lossy();
+            run_sample(&binary, input, output, checker, timeout, &name)
+        })
+        .collect();
+    let (summary, success) = summarize(&verdicts);
+    println!("{}", summary);
+    if !success {
+        exit(1);
+    }
+}
+
+/// The final line, and whether all the samples which were run passed (and at least one of them was run).
+fn summarize(verdicts: &[Verdict]) -> (String, bool) {
+    let count = |verdict| verdicts.iter().filter(|&&v| v == verdict).count();
+    let (passed, skipped) = (count(Verdict::Passed), count(Verdict::Skipped));
+    let run = verdicts.len() - skipped;
+    let mut summary = format!("Passed {}/{}", passed, run);
+    if skipped > 0 {
+        summary += &format!(" ({} skipped)", skipped);
+    }
+    (summary, run > 0 && passed == run)
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: sample_runner <solution binary or .rs> <samples dir> [--eps <eps>] [--timeout <seconds>]"
+    );
+    exit(2);
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    exit(1);
+}
+
+fn compile(source: &Path) -> PathBuf {
+    let dir = std::env::temp_dir().join("sample_runner");
+    std::fs::create_dir_all(&dir)
+        .unwrap_or_else(|e| fail(&format!("Cannot create {}: {}", dir.display(), e)));
+    let binary = dir.join(source.file_stem().unwrap());
+    eprintln!("Compiling {}", source.display());
+    let status = Command::new("rustc")
+        .args(["--edition", "2021", "-O", "-o"])
+        .arg(&binary)
+        .arg(source)
+        .status()
+        .unwrap_or_else(|e| fail(&format!("Cannot run rustc: {}", e)));
+    if !status.success() {
+        fail("Compilation failed");
+    }
+    binary
+}
+
+/// The `*.in` files with their `*.out` files, ordered by the number in the name (`2.in` before `10.in`).
+fn collect_samples(dir: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| fail(&format!("Cannot read {}: {}", dir.display(), e)));
+    let mut inputs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "in"))
+        .collect();
+    inputs.sort_by_key(|path| {
+        let stem = path.file_stem().unwrap().to_string_lossy().to_string();
+        (stem.parse::<u64>().unwrap_or(u64::MAX), stem)
+    });
+    inputs
+        .into_iter()
+        .map(|input| {
+            let output = input.with_extension("out");
+            (input, output)
+        })
+        .collect()
+}
+
+struct Run {
+    stdout: String,
+    stderr: String,
+    status: Option<ExitStatus>,
+    elapsed: Duration,
+}
+
+/// Runs the binary with `-DEBUG`, killing it after the timeout (`status` is `None` then).
+fn run(binary: &Path, input: Vec<u8>, timeout: Option<Duration>) -> Run {
+    let start = Instant::now();
+    let mut child = Command::new(binary)
+        .arg("-DEBUG")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| fail(&format!("Cannot run {}: {}", binary.display(), e)));
+
+    // Writing and reading in separate threads, as the pipes might fill up otherwise
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = thread::spawn(move || {
+        // The solution might exit without reading the whole input
+        let _ = stdin.write_all(&input);
+    });
+    let read_all = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut buf = vec![];
+            let _ = pipe.read_to_end(&mut buf);
+            String::from_utf8_lossy(&buf).into_owned()
+        })
+    };
+    let stdout = read_all(Box::new(child.stdout.take().unwrap()));
+    let stderr = read_all(Box::new(child.stderr.take().unwrap()));
+
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break Some(status);
+        }
+        if timeout.is_some_and(|timeout| start.elapsed() > timeout) {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(Duration::from_millis(1));
+    };
+    let elapsed = start.elapsed();
+    writer.join().unwrap();
+
+    Run {
+        stdout: stdout.join().unwrap(),
+        stderr: stderr.join().unwrap(),
+        status,
+        elapsed,
+    }
+}
+
+/// Runs a single sample file and prints the verdict.
+fn run_sample(
+    binary: &Path,
+    input: &Path,
+    output: &Path,
+    checker: Checker,
+    timeout: Option<Duration>,
+    name: &str,
+) -> Verdict {
+    let input_bytes = std::fs::read(input)
+        .unwrap_or_else(|e| fail(&format!("Cannot read {}: {}", input.display(), e)));
+    let expected = match std::fs::read_to_string(output) {
+        Ok(expected) => expected,
+        Err(_) => {
+            println!("{}: SKIPPED (no {})", name, output.display());
+            return Verdict::Skipped;
+        }
+    };
+
+    let run = run(binary, input_bytes, timeout);
+    let ms = run.elapsed.as_millis();
+    let error = match (run.status, panic_report(&run.stderr)) {
+        (None, _) => Some("TIME LIMIT EXCEEDED".to_string()),
+        (Some(status), _) if !status.success() => Some(format!("RUNTIME ERROR ({})", status)),
+        (_, Some(panic)) => Some(format!("RUNTIME ERROR ({})", panic)),
+        _ => None,
+    };
+    if let Some(error) = error {
+        println!("{}: {} ({} ms)", name, error, ms);
+        print_stderr(&run.stderr);
+        return Verdict::Failed;
+    }
+
+    match compare(&expected, &run.stdout, checker) {
+        None => {
+            println!("{}: OK ({} ms)", name, ms);
+            Verdict::Passed
+        }
+        Some(report) => {
+            println!("{}: WRONG ANSWER ({} ms)", name, ms);
+            println!("{}", report);
+            print_stderr(&run.stderr);
+            Verdict::Failed
+        }
+    }
+}
+
+/// The report of the first test case that panicked, printed by the template in the debug mode.
+fn panic_report(stderr: &str) -> Option<&str> {
+    stderr
+        .lines()
+        .find(|line| line.starts_with("Test case #") && line.contains(" panicked after "))
+}
+
+fn print_stderr(stderr: &str) {
+    if !stderr.trim().is_empty() {
+        println!("Stderr:");
+        println!("{}", stderr.trim_end());
+    }
+}
+
+/// The outputs of the concatenated inputs.
+fn split_outputs(output: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    for line in output.lines() {
+        if line.trim() == SEPARATOR {
+            parts.push(String::new());
+        } else {
+            let part = parts.last_mut().unwrap();
+            part.push_str(line);
+            part.push('\n');
+        }
+    }
+    parts
+}
+
+/// Returns the report of the first difference, if any.
+fn compare(expected: &str, found: &str, checker: Checker) -> Option<String> {
+    let expected_parts = split_outputs(expected);
+    let found_parts = split_outputs(found);
+    if expected_parts.len() == 1 {
+        return compare_part(&expected_parts[0], &found_parts.concat(), checker);
+    }
+    if expected_parts.len() != found_parts.len() {
+        return Some(format!(
+            "Expected {} outputs (separated by `{}`), found {}",
+            expected_parts.len(),
+            SEPARATOR,
+            found_parts.len()
+        ));
+    }
+    expected_parts
+        .iter()
+        .zip(&found_parts)
+        .enumerate()
+        .find_map(|(i, (expected, found))| {
+            compare_part(expected, found, checker)
+                .map(|report| format!("In input #{}:\n{}", i + 1, report))
+        })
+}
+
+/// The tokens, with the (0-based) line they're in.
+fn tokens(text: &str) -> Vec<(usize, &str)> {
+    text.lines()
+        .enumerate()
+        .flat_map(|(i, line)| line.split_whitespace().map(move |token| (i, token)))
+        .collect()
+}
+
+fn tokens_match(expected: &str, found: &str, checker: Checker) -> bool {
+    if expected == found {
+        return true;
+    }
+    match checker {
+        Checker::Tokens => false,
+        Checker::Float(eps) => match (expected.parse::<f64>(), found.parse::<f64>()) {
+            (Ok(a), Ok(b)) => {
+                let diff = (a - b).abs();
+                diff <= eps || diff <= eps * a.abs()
+            }
+            _ => false,
+        },
+    }
+}
+
+fn compare_part(expected: &str, found: &str, checker: Checker) -> Option<String> {
+    let expected_tokens = tokens(expected);
+    let found_tokens = tokens(found);
+    let i = (0..expected_tokens.len().max(found_tokens.len())).find(|&i| {
+        match (expected_tokens.get(i), found_tokens.get(i)) {
+            (Some(a), Some(b)) => !tokens_match(a.1, b.1, checker),
+            _ => true,
+        }
+    })?;
+
+    let describe = |token: Option<&(usize, &str)>| match token {
+        Some((line, token)) => format!("`{}` (line {})", token, line + 1),
+        None => "the end of the output".to_string(),
+    };
+    let line_of = |tokens: &[(usize, &str)], text: &str| {
+        tokens
+            .get(i)
+            .map_or(text.lines().count().saturating_sub(1), |token| token.0)
+    };
+    Some(format!(
+        "Token #{}: expected {}, found {}\nExpected:\n{}Found:\n{}",
+        i + 1,
+        describe(expected_tokens.get(i)),
+        describe(found_tokens.get(i)),
+        excerpt(expected, line_of(&expected_tokens, expected)),
+        excerpt(found, line_of(&found_tokens, found)),
+    ))
+}
+
+/// The lines around `line` with their numbers, marking `line`.
+fn excerpt(text: &str, line: usize) -> String {
+    let mut excerpt = String::new();
+    for (i, content) in text
+        .lines()
+        .enumerate()
+        .skip(line.saturating_sub(DIFF_CONTEXT))
+        .take(2 * DIFF_CONTEXT + 1)
+    {
+        let marker = if i == line { '>' } else { ' ' };
+        excerpt.push_str(&format!("{} {:4} | {}\n", marker, i + 1, content));
+    }
+    excerpt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_match_within_eps() {
+        let eps = Checker::Float(1e-6);
+        assert!(tokens_match("abc", "abc", Checker::Tokens));
+        assert!(!tokens_match("1.0", "1", Checker::Tokens));
+        assert!(tokens_match("1.0", "1", eps));
+        // Absolute error
+        assert!(tokens_match("0", "0.0000005", eps));
+        assert!(!tokens_match("0", "0.000002", eps));
+        // Relative error
+        assert!(tokens_match("1000000000", "1000000500", eps));
+        assert!(!tokens_match("1000000000", "1000002000", eps));
+        assert!(tokens_match("-1e9", "-1000000500", eps));
+
+        assert!(tokens_match("NaN", "NaN", eps));
+        assert!(!tokens_match("NaN", "nan", eps));
+        assert!(!tokens_match("1", "NaN", eps));
+        assert!(!tokens_match("NaN", "1", eps));
+        assert!(!tokens_match("Yes", "yes", eps));
+        assert!(!tokens_match("1", "one", eps));
+    }
+
+    #[test]
+    fn splits_outputs_of_concatenated_inputs() {
+        assert_eq!(split_outputs("1 2\n3\n"), ["1 2\n3\n"]);
+        assert_eq!(
+            split_outputs("1\nNext Input:\n2\n3\n Next Input: \n"),
+            ["1\n", "2\n3\n", ""]
+        );
+
+        let expected = "1\nNext Input:\n2\n";
+        assert_eq!(
+            compare(expected, "1\nNext Input:\n2\n", Checker::Tokens),
+            None
+        );
+        // Without a separator in the expected output, everything is compared together
+        assert_eq!(
+            compare("1\n2\n", "1\nNext Input:\n2\n", Checker::Tokens),
+            None
+        );
+        let report = compare(expected, "1\n2\n", Checker::Tokens).unwrap();
+        assert_eq!(
+            report,
+            "Expected 2 outputs (separated by `Next Input:`), found 1"
+        );
+        let report = compare(expected, "1\nNext Input:\n3\n", Checker::Tokens).unwrap();
+        assert!(
+            report.starts_with("In input #2:\nToken #1: expected `2` (line 1), found `3` (line 1)")
+        );
+    }
+
+    #[test]
+    fn collects_samples_in_numeric_order() {
+        let dir = std::env::temp_dir().join(format!("sample_runner_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "10.in",
+            "2.in",
+            "1.in",
+            "b.in",
+            "a.in",
+            "2.out",
+            "notes.txt",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let samples = collect_samples(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<String> = samples
+            .iter()
+            .map(|(input, _)| input.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["1.in", "2.in", "10.in", "a.in", "b.in"]);
+        assert!(samples
+            .iter()
+            .all(|(input, output)| *output == input.with_extension("out")));
+    }
+
+    #[test]
+    fn skipped_samples_are_not_passes() {
+        let dir = std::env::temp_dir().join(format!("sample_runner_skip_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("1.in"), "").unwrap();
+        let verdict = run_sample(
+            Path::new("missing_binary"),
+            &dir.join("1.in"),
+            &dir.join("1.out"),
+            Checker::Tokens,
+            None,
+            "1.in",
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(verdict, Verdict::Skipped);
+
+        use Verdict::*;
+        assert_eq!(
+            summarize(&[Passed, Passed]),
+            ("Passed 2/2".to_string(), true)
+        );
+        assert_eq!(
+            summarize(&[Passed, Skipped]),
+            ("Passed 1/1 (1 skipped)".to_string(), true)
+        );
+        assert_eq!(
+            summarize(&[Failed, Skipped, Passed]),
+            ("Passed 1/2 (1 skipped)".to_string(), false)
+        );
+        assert_eq!(
+            summarize(&[Skipped]),
+            ("Passed 0/0 (1 skipped)".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn detects_the_panic_report() {
+        let stderr = "Test case #1 took 1.2ms\n\
+            thread 'main' panicked at src/main.rs:5:9:\n\
+            index out of bounds\n\
+            Test case #2 panicked after 3.4ms: index out of bounds\n\
+            Test case #3 panicked after 1ms: again\n";
+        assert_eq!(
+            panic_report(stderr),
+            Some("Test case #2 panicked after 3.4ms: index out of bounds")
+        );
+        assert_eq!(
+            panic_report("Test case #1 took 1.2ms\nTest case #2 took 1ms\n"),
+            None
+        );
+        assert_eq!(
+            panic_report("debug: Test case #1 panicked after 1ms\n"),
+            None
+        );
+        assert_eq!(panic_report(""), None);
+    }
+}
@@ -0,0 +1,104 @@
+//! Compares `input::<i64>()` with `input_int::<i64>()`, for both `StreamScanner` and `WholeInputScanner`.
+//!
+//! The scanners always read from stdin, so the benchmark writes the tokens to a temporary file and
+//! re-runs itself with that file as stdin. Run it with:
+//!
+//! `cargo bench --bench scanner`
+//!
+//! Pass the token counts as arguments (after `--`) to override the default `1000000 10000000`.
+
+use competitive_programming::template::custom_io::{
+    ScannerOperations, StreamScanner, WholeInputScanner,
+};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::process::{Command, Stdio};
+use std::time::Instant;
+
+const MODES: [&str; 4] = [
+    "stream_input",
+    "stream_input_int",
+    "whole_input",
+    "whole_input_int",
+];
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() == 4 && args[1] == "--child" {
+        child(&args[2], args[3].parse().unwrap());
+        return;
+    }
+
+    // `cargo bench` passes `--bench` to the benchmarks
+    let args: Vec<&String> = args.iter().skip(1).filter(|x| *x != "--bench").collect();
+    let counts: Vec<usize> = if !args.is_empty() {
+        args.iter().map(|x| x.parse().unwrap()).collect()
+    } else {
+        vec![1_000_000, 10_000_000]
+    };
+
+    for n in counts {
+        let path = std::env::temp_dir().join(format!("scanner_bench_{}.txt", n));
+        generate(&path, n);
+        for mode in MODES {
+            let output = Command::new(std::env::current_exe().unwrap())
+                .args(["--child", mode, &n.to_string()])
+                .stdin(File::open(&path).unwrap())
+                .stderr(Stdio::inherit())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "The child process failed!");
+            print!(
+                "{:>10} tokens, {:>16}: {}",
+                n,
+                mode,
+                String::from_utf8_lossy(&output.stdout)
+            );
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+}
+
+/// Writes `n` pseudo-random integers in `[-10^9, 10^9]`.
+fn generate(path: &std::path::Path, n: usize) {
+    let mut out = BufWriter::new(File::create(path).unwrap());
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    writeln!(out, "{}", n).unwrap();
+    for i in 0..n {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let x = (state % 2_000_000_001) as i64 - 1_000_000_000;
+        write!(out, "{}{}", x, if i % 10 == 9 { '\n' } else { ' ' }).unwrap();
+    }
+}
+
+fn child(mode: &str, n: usize) {
+    match mode {
+        "stream_input" | "stream_input_int" => read(StreamScanner::new(), mode, n),
+        "whole_input" | "whole_input_int" => read(WholeInputScanner::new(), mode, n),
+        _ => panic!("Unknown mode {}", mode),
+    }
+}
+
+/// Excludes the time taken in creating the scanner (which reads the whole input in case of `WholeInputScanner`).
+fn read<S: ScannerOperations>(mut sc: S, mode: &str, n: usize) {
+    let start = Instant::now();
+    let mut sum = 0i64;
+    if mode.ends_with("_int") {
+        assert_eq!(sc.input_int::<usize>(), n);
+        for _ in 0..n {
+            sum = sum.wrapping_add(sc.input_int::<i64>());
+        }
+    } else {
+        assert_eq!(sc.input::<usize>(), n);
+        for _ in 0..n {
+            sum = sum.wrapping_add(sc.input::<i64>());
+        }
+    }
+    println!(
+        "{:>8.2} ms (checksum {})",
+        start.elapsed().as_secs_f64() * 1e3,
+        sum
+    );
+}
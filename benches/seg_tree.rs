@@ -0,0 +1,82 @@
+//! Compares the recursive `LazySegmentTree` with the `IterativeLazySegmentTree`, on the same random range add and
+//! range sum operations. Run it with:
+//!
+//! `cargo bench --bench seg_tree`
+//!
+//! Pass the sizes as arguments (after `--`) to override the default `100000 1000000` (the number of operations is
+//! the same as the size).
+
+use competitive_programming::seg_tree::*;
+use competitive_programming::seg_tree_ops::AddSum;
+use std::time::Instant;
+
+/// `(is_update, l, r, x)`, with `l <= r < n`.
+fn generate(n: usize) -> Vec<(bool, usize, usize, i64)> {
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    (0..n)
+        .map(|_| {
+            let (a, b) = (next() as usize % n, next() as usize % n);
+            let x = (next() % 2001) as i64 - 1000;
+            (next() & 1 == 1, a.min(b), a.max(b), x)
+        })
+        .collect()
+}
+
+fn main() {
+    // `cargo bench` passes `--bench` to the benchmarks
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|x| x != "--bench")
+        .collect();
+    let sizes: Vec<usize> = if !args.is_empty() {
+        args.iter().map(|x| x.parse().unwrap()).collect()
+    } else {
+        vec![100_000, 1_000_000]
+    };
+
+    for n in sizes {
+        let ops = generate(n);
+        let a: Vec<i64> = ops.iter().map(|op| op.3).collect();
+
+        let start = Instant::now();
+        let mut tree = LazySegmentTree::<AddSum<i64>>::with_nodes(a.clone());
+        let mut recursive = 0i64;
+        for &(is_update, l, r, x) in &ops {
+            if is_update {
+                tree.update(l..=r, x);
+            } else {
+                recursive = recursive.wrapping_add(tree.query(l..=r));
+            }
+        }
+        report(n, "recursive", start, recursive);
+
+        let start = Instant::now();
+        let mut tree = IterativeLazySegmentTree::<AddSum<i64>>::with_nodes(a);
+        let mut iterative = 0i64;
+        for &(is_update, l, r, x) in &ops {
+            if is_update {
+                tree.update(l..=r, x);
+            } else {
+                iterative = iterative.wrapping_add(tree.query(l..=r));
+            }
+        }
+        report(n, "iterative", start, iterative);
+        assert_eq!(recursive, iterative, "The trees gave different answers!");
+    }
+}
+
+fn report(n: usize, name: &str, start: Instant, checksum: i64) {
+    println!(
+        "{:>8} elements, {:>9}: {:>8.2} ms (checksum {})",
+        n,
+        name,
+        start.elapsed().as_secs_f64() * 1e3,
+        checksum
+    );
+}
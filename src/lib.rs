@@ -0,0 +1,58 @@
+//! Every module of the repo, included at the crate root (just like they are pasted into a solution), so that the
+//! `super::` paths between them resolve as they would in a submission. The files are included as they are, and are
+//! tested in the `tests` directory. The bugs found by the tests are fixed in the modules themselves, each with a
+//! regression test.
+
+// Each module is followed by a glob import for the solution, which is unused here. The modules also keep some
+// variables and items for the commented out customizations (just like the template).
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+// `new()` reads the input for the scanners and precomputes the tables for the others, so a `Default` would be
+// misleading. Division is done by multiplying with the inverse, and the rest are kept as written for the contests.
+#![allow(clippy::new_without_default)]
+#![allow(clippy::suspicious_op_assign_impl)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::needless_range_loop)]
+
+include!("../CombinedRustModules/my_utils.rs");
+include!("../CombinedRustModules/math.rs");
+
+include!("../BinarySearch/bs.rs");
+
+include!("../DataStructures/DSU/dsu.rs");
+include!("../DataStructures/FenwickTree/fenwick.rs");
+include!("../DataStructures/FenwickTree/fenwick_2d.rs");
+include!("../DataStructures/IntervalContainer/interval_container.rs");
+include!("../DataStructures/MonotonicStacks/monotonic_stacks.rs");
+include!("../DataStructures/SegmentTree/seg_tree.rs");
+include!("../DataStructures/SegmentTree/dynamic_seg_tree.rs");
+include!("../DataStructures/SegmentTree/persistent_seg_tree.rs");
+include!("../DataStructures/SegmentTree/seg_tree_beats.rs");
+include!("../DataStructures/SegmentTree/seg_tree_2d.rs");
+include!("../DataStructures/SegmentTree/seg_tree_ops.rs");
+include!("../DataStructures/SparseTable/sparse.rs");
+include!("../DataStructures/WaveletMatrix/wavelet_matrix.rs");
+
+include!("../Math/Fraction/fraction.rs");
+include!("../Math/Matrix/matrix.rs");
+include!("../Math/ModInt/mod_int.rs");
+include!("../Math/Sieve/sieve.rs");
+
+include!("../Graph/graph.rs");
+include!("../Graph/scc.rs");
+include!("../Graph/Grid/grid.rs");
+include!("../Graph/Tree/tree.rs");
+include!("../Graph/Tree/LCA/lca.rs");
+include!("../Graph/Tree/centroid_decomposition.rs");
+
+include!("../TwoSat/two_sat.rs");
+
+include!("../String/polynomial_hash.rs");
+include!("../String/string_algos.rs");
+
+include!("../Template/interactor.rs");
+include!("../Template/stress.rs");
+
+#[path = "../Template/Main.rs"]
+pub mod template;
@@ -0,0 +1,47 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::bs;
+
+#[test]
+fn integer_searches_match_linear_scan() {
+    let mut rng = Rng::new(1);
+    for _ in 0..500 {
+        let l = rng.range(-50, 50);
+        let r = rng.range(l, 60);
+        // Monotonic predicate: false till `threshold - 1`, true from `threshold`
+        let threshold = rng.range(l - 3, r + 3);
+        let pred = |x: i64| x >= threshold;
+
+        let first_true = (l..=r).find(|&x| pred(x)).unwrap_or(r + 1);
+        assert_eq!(bs::first_true(l, r, pred), first_true);
+        assert_eq!(bs::last_false(l, r, pred), first_true - 1);
+
+        let not_pred = |x: i64| x < threshold;
+        assert_eq!(bs::first_false(l, r, not_pred), first_true);
+        assert_eq!(bs::last_true(l, r, not_pred), first_true - 1);
+    }
+}
+
+#[test]
+fn unsigned_search_near_the_limits() {
+    assert_eq!(
+        bs::first_true(0u64, 1 << 31, |x| x * x >= 1_000_000_007),
+        31623
+    );
+    assert_eq!(bs::last_true(0usize, 100, |x| x * x <= 50), 7);
+    assert_eq!(bs::first_true(0u32, 10, |_| false), 11);
+}
+
+#[test]
+fn floating_searches() {
+    let sqrt2 = bs::first_true_floating_with_epsilon(0.0, 2.0, 1e-9, |x| x * x >= 2.0);
+    assert!((sqrt2 - 2f64.sqrt()).abs() < 1e-8);
+    let sqrt2 = bs::first_true_floating_with_iterations(0.0, 2.0, 100, |x| x * x >= 2.0);
+    assert!((sqrt2 - 2f64.sqrt()).abs() < 1e-12);
+
+    let cbrt = bs::first_false_floating_with_epsilon(0.0, 10.0, 1e-9, |x| x * x * x < 10.0);
+    assert!((cbrt - 10f64.cbrt()).abs() < 1e-8);
+    let cbrt = bs::first_false_floating_with_iterations(0.0, 10.0, 100, |x| x * x * x < 10.0);
+    assert!((cbrt - 10f64.cbrt()).abs() < 1e-12);
+}
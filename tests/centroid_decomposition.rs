@@ -0,0 +1,75 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::centroid_decomposition::*;
+use competitive_programming::tree::*;
+
+/// The size of the component containing `u` after removing `removed`.
+fn component_size(tree: &Tree, u: usize, removed: usize) -> usize {
+    let mut visited = vec![false; tree.n];
+    visited[removed] = true;
+    visited[u] = true;
+    let mut stack = vec![u];
+    let mut size = 0;
+    while let Some(u) = stack.pop() {
+        size += 1;
+        for &v in &tree.adj[u] {
+            if !visited[v as usize] {
+                visited[v as usize] = true;
+                stack.push(v as usize);
+            }
+        }
+    }
+    size
+}
+
+#[test]
+fn root_is_a_centroid() {
+    let mut rng = Rng::new(1);
+    for _ in 0..200 {
+        let n = rng.usize(1, 50);
+        let mut tree = Tree::new(n);
+        for (v, p) in rng.tree_parents(n).into_iter().enumerate().skip(1) {
+            tree.add_edge(p, v);
+            tree.add_edge(v, p);
+        }
+        tree.dfs(0, None);
+
+        let centroid_tree = CentroidTree::from_tree(&tree);
+        let root = centroid_tree.root;
+        assert_eq!(centroid_tree.n, n);
+        for &v in &tree.adj[root] {
+            assert!(component_size(&tree, v as usize, root) <= n / 2);
+        }
+    }
+}
+
+#[test]
+fn path() {
+    let mut tree = Tree::new(7);
+    for u in 1..7 {
+        tree.add_edge(u - 1, u);
+        tree.add_edge(u, u - 1);
+    }
+    assert_eq!(CentroidTree::from_tree(&tree).root, 3);
+}
+
+#[test]
+fn size_is_kept_from_the_last_component() {
+    // The centroid 1 is found with the sizes rooted at 0, then 0 and 2 are single nodes
+    let mut tree = Tree::new(3);
+    for u in 1..3 {
+        tree.add_edge(u - 1, u);
+        tree.add_edge(u, u - 1);
+    }
+    let centroid_tree = CentroidTree::from_tree(&tree);
+    assert_eq!(centroid_tree.root, 1);
+    assert_eq!(centroid_tree.size, [1, 2, 1]);
+
+    let mut star = Tree::new(5);
+    for v in 1..5 {
+        star.add_edge(0, v);
+        star.add_edge(v, 0);
+    }
+    assert_eq!(CentroidTree::from_tree(&star).size, [5, 1, 1, 1, 1]);
+}
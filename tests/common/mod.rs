@@ -0,0 +1,81 @@
+//! Helpers shared by the tests (each test file is its own crate, so not all of them are used everywhere).
+#![allow(dead_code)]
+
+use competitive_programming::seg_tree::{LazySegmentTreeOperations, SegmentTreeOperations};
+
+/// A small xorshift generator, so that the tests are reproducible without any dependency.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform in `[l, r]`.
+    pub fn range(&mut self, l: i64, r: i64) -> i64 {
+        l + (self.next_u64() % (r - l + 1) as u64) as i64
+    }
+
+    pub fn usize(&mut self, l: usize, r: usize) -> usize {
+        self.range(l as i64, r as i64) as usize
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn vec(&mut self, n: usize, l: i64, r: i64) -> Vec<i64> {
+        (0..n).map(|_| self.range(l, r)).collect()
+    }
+
+    /// A random (inclusive) range in `[0, n - 1]`.
+    pub fn segment(&mut self, n: usize) -> (usize, usize) {
+        let a = self.usize(0, n - 1);
+        let b = self.usize(0, n - 1);
+        (a.min(b), a.max(b))
+    }
+
+    /// The parent of every node other than the root `0`, with `parent[i] < i`.
+    pub fn tree_parents(&mut self, n: usize) -> Vec<usize> {
+        (0..n)
+            .map(|i| if i == 0 { 0 } else { self.usize(0, i - 1) })
+            .collect()
+    }
+}
+
+/// Range add, range sum, using the length `r - l + 1` of the node (so that it also checks the range passed to
+/// `apply`). The `seg_tree_ops` ones keep the length in the data instead.
+pub struct AddSum;
+impl SegmentTreeOperations for AddSum {
+    type Data = i64;
+
+    fn data_identity() -> Self::Data {
+        0
+    }
+
+    fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+        a + b
+    }
+}
+impl LazySegmentTreeOperations for AddSum {
+    type Lazy = i64;
+
+    fn lazy_identity() -> Self::Lazy {
+        0
+    }
+
+    fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data {
+        data + update * (r - l + 1) as i64
+    }
+
+    fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+        prev + next
+    }
+}
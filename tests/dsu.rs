@@ -0,0 +1,30 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::dsu::DSU;
+
+#[test]
+fn matches_naive_labels() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut dsu = DSU::new(n);
+        let mut label: Vec<usize> = (0..n).collect();
+        for _ in 0..2 * n {
+            let (a, b) = (rng.usize(0, n - 1), rng.usize(0, n - 1));
+            let (la, lb) = (label[a], label[b]);
+            assert_eq!(dsu.merge(a, b), la != lb);
+            for x in label.iter_mut() {
+                if *x == lb {
+                    *x = la;
+                }
+            }
+
+            for u in 0..n {
+                let size = label.iter().filter(|&&x| x == label[u]).count();
+                assert_eq!(dsu.size(u) as usize, size);
+                assert_eq!(dsu.parent(u) == dsu.parent(a), label[u] == label[a]);
+            }
+        }
+    }
+}
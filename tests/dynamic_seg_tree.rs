@@ -0,0 +1,212 @@
+mod common;
+
+use common::{AddSum, Rng};
+use competitive_programming::dynamic_seg_tree::*;
+use competitive_programming::seg_tree::*;
+use competitive_programming::seg_tree_ops::{AddMax, Max, Sum};
+use std::collections::BTreeMap;
+
+/// Range add, range sum, wrapping around as the sums over the huge ranges overflow
+struct WrappingAddSum;
+impl SegmentTreeOperations for WrappingAddSum {
+    type Data = i64;
+
+    fn data_identity() -> Self::Data {
+        0
+    }
+
+    fn merge(a: &Self::Data, b: &Self::Data) -> Self::Data {
+        a.wrapping_add(*b)
+    }
+}
+impl LazySegmentTreeOperations for WrappingAddSum {
+    type Lazy = i64;
+
+    fn lazy_identity() -> Self::Lazy {
+        0
+    }
+
+    fn apply(data: &Self::Data, update: &Self::Lazy, l: usize, r: usize) -> Self::Data {
+        data.wrapping_add(update.wrapping_mul((r - l + 1) as i64))
+    }
+
+    fn compose(prev: &Self::Lazy, next: &Self::Lazy) -> Self::Lazy {
+        prev + next
+    }
+}
+
+/// A random range in `[lo, lo + n - 1]`.
+fn segment(rng: &mut Rng, lo: i64, n: usize) -> (i64, i64) {
+    let (l, r) = rng.segment(n);
+    (lo + l as i64, lo + r as i64)
+}
+
+#[test]
+fn point_update_matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let lo = rng.range(-50, 50);
+        let initial = rng.range(-5, 5);
+        let mut a = vec![initial; n];
+        let mut sum =
+            DynamicSegmentTree::<Sum<i64>, i64>::with_value(lo, lo + n as i64 - 1, initial);
+        let mut max =
+            DynamicSegmentTree::<Max<i64>, i64>::with_value(lo, lo + n as i64 - 1, initial);
+        for _ in 0..100 {
+            let (l, r) = segment(&mut rng, lo, n);
+            let (il, ir) = ((l - lo) as usize, (r - lo) as usize);
+            match rng.usize(0, 3) {
+                0 => {
+                    let x = rng.range(-20, 20);
+                    a[il] += x;
+                    sum.update(l, |c| c + x);
+                    max.update(l, |c| c + x);
+                }
+                1 => {
+                    let x = rng.range(-20, 20);
+                    a[il] = x;
+                    sum.set(l, x);
+                    max.set(l, x);
+                }
+                2 => {
+                    assert_eq!(sum.query(l..=r), a[il..=ir].iter().sum::<i64>());
+                    assert_eq!(max.query(l..r + 1), *a[il..=ir].iter().max().unwrap());
+                    assert_eq!(sum.get(l), a[il]);
+                }
+                _ => {
+                    let x = rng.range(-20, 20);
+                    let first = (il..=ir).find(|&i| a[i] >= x).map(|i| lo + i as i64);
+                    let last = (il..=ir).rev().find(|&i| a[i] >= x).map(|i| lo + i as i64);
+                    assert_eq!(max.first_true(l..=r, |&m| m >= x), first);
+                    assert_eq!(max.last_true(l..=r, |&m| m >= x), last);
+                }
+            }
+        }
+        assert_eq!(sum.query(..), a.iter().sum::<i64>());
+        assert_eq!(sum.query(lo..lo), 0);
+    }
+}
+
+#[test]
+fn lazy_matches_brute_force() {
+    let mut rng = Rng::new(2);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let lo = rng.range(-50, 50);
+        let initial = rng.range(-5, 5);
+        let mut a = vec![initial; n];
+        let hi = lo + n as i64 - 1;
+        let mut sum = DynamicLazySegmentTree::<AddSum, i64>::with_value(lo, hi, initial);
+        let mut max = DynamicLazySegmentTree::<AddMax<i64>, i64>::with_value(lo, hi, initial);
+        for _ in 0..100 {
+            let (l, r) = segment(&mut rng, lo, n);
+            let (il, ir) = ((l - lo) as usize, (r - lo) as usize);
+            match rng.usize(0, 2) {
+                0 => {
+                    let x = rng.range(-20, 20);
+                    a[il..=ir].iter_mut().for_each(|y| *y += x);
+                    sum.update(l..=r, x);
+                    max.update(l..r + 1, x);
+                }
+                1 => {
+                    assert_eq!(sum.query(l..=r), a[il..=ir].iter().sum::<i64>());
+                    assert_eq!(max.query(l..=r), *a[il..=ir].iter().max().unwrap());
+                }
+                _ => {
+                    let x = rng.range(-30, 30);
+                    let first = (il..=ir).find(|&i| a[i] >= x).map(|i| lo + i as i64);
+                    let last = (il..=ir).rev().find(|&i| a[i] >= x).map(|i| lo + i as i64);
+                    assert_eq!(max.first_true(l..=r, |&m| m >= x), first);
+                    assert_eq!(max.last_true(l..=r, |&m| m >= x), last);
+                }
+            }
+        }
+        sum.update(.., 1);
+        a.iter_mut().for_each(|y| *y += 1);
+        assert_eq!(sum.query(..), a.iter().sum::<i64>());
+    }
+}
+
+#[test]
+fn huge_ranges_match_brute_force() {
+    const LIMIT: i64 = 1_000_000_000_000_000_000;
+    let mut rng = Rng::new(3);
+    for _ in 0..20 {
+        let mut a = BTreeMap::new();
+        let mut sum = DynamicSegmentTree::<Sum<i64>, i64>::new(-LIMIT, LIMIT);
+        let mut max = DynamicSegmentTree::<Max<i64>, u64>::new(0, 2 * LIMIT as u64);
+        for _ in 0..200 {
+            let pos = rng.range(-LIMIT, LIMIT);
+            let x = rng.range(-100, 100);
+            *a.entry(pos).or_insert(0) += x;
+            sum.update(pos, |c| c + x);
+            max.set((pos + LIMIT) as u64, a[&pos]);
+
+            let (l, r) = (rng.range(-LIMIT, LIMIT), rng.range(-LIMIT, LIMIT));
+            let (l, r) = (l.min(r), l.max(r));
+            assert_eq!(
+                sum.query(l..=r),
+                a.range(l..=r).map(|(_, x)| x).sum::<i64>()
+            );
+            let expected = a.range(l..=r).map(|(_, &x)| x).max().unwrap_or(i64::MIN);
+            assert_eq!(max.query((l + LIMIT) as u64..=(r + LIMIT) as u64), expected);
+            let first = a.range(l..=r).find(|(_, &x)| x > 0).map(|(&i, _)| i);
+            assert_eq!(
+                max.first_true((l + LIMIT) as u64..=(r + LIMIT) as u64, |&m| m > 0),
+                first.map(|i| (i + LIMIT) as u64)
+            );
+        }
+        // 200 updates, each creating at most 61 nodes
+        assert!(sum.node_count() <= 1 + 200 * 61);
+    }
+}
+
+#[test]
+fn lazy_over_huge_ranges() {
+    let mut rng = Rng::new(4);
+    let mut tree = DynamicLazySegmentTree::<WrappingAddSum, u64>::new(0, i64::MAX as u64);
+    let mut updates: Vec<(u64, u64, i64)> = vec![];
+    for _ in 0..200 {
+        let (l, r) = (rng.next_u64() >> 1, rng.next_u64() >> 1);
+        let (l, r) = (l.min(r), l.max(r));
+        if rng.bool() {
+            let x = rng.range(-5, 5);
+            tree.update(l..=r, x);
+            updates.push((l, r, x));
+        } else {
+            // Only the sum over a small range fits in an i64
+            let r = (l + rng.range(0, 1000) as u64).min(i64::MAX as u64);
+            let expected: i64 = updates
+                .iter()
+                .map(|&(ul, ur, x)| {
+                    let (il, ir) = (ul.max(l), ur.min(r));
+                    if il <= ir {
+                        x * (ir - il + 1) as i64
+                    } else {
+                        0
+                    }
+                })
+                .sum();
+            assert_eq!(tree.query(l..=r), expected);
+        }
+    }
+    assert_eq!(tree.query(5..5), 0);
+}
+
+#[test]
+fn lazy_root_gets_its_length() {
+    // `2^63` indices, the length of the root wraps around to `i64::MIN` in the sum
+    let mut tree = DynamicLazySegmentTree::<WrappingAddSum, u64>::new(0, i64::MAX as u64);
+    tree.update(.., 1);
+    assert_eq!(tree.query(..), i64::MIN);
+    assert_eq!(tree.query(10..20), 10);
+    tree.update(.., 1);
+    assert_eq!(tree.query(..), 0);
+}
+
+#[test]
+#[should_panic]
+fn lazy_rejects_lengths_overflowing_usize() {
+    DynamicLazySegmentTree::<WrappingAddSum, u64>::new(0, u64::MAX);
+}
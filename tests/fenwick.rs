@@ -0,0 +1,33 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::fenwick::*;
+
+#[test]
+fn prefix_sums_match_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 50);
+        let mut fenwick = FenwickTree::<i64>::new(n);
+        let mut a = vec![0i64; n];
+        for _ in 0..100 {
+            let i = rng.usize(0, n - 1);
+            if rng.bool() {
+                let x = rng.range(-100, 100);
+                fenwick.add(i, x);
+                a[i] += x;
+            } else {
+                assert_eq!(fenwick.get(i), a[..=i].iter().sum::<i64>());
+            }
+        }
+    }
+}
+
+#[test]
+fn works_with_floats() {
+    let mut fenwick = FenwickTree::<f64>::new(3);
+    fenwick.add(0, 0.5);
+    fenwick.add(2, 0.25);
+    assert_eq!(fenwick.get(1), 0.5);
+    assert_eq!(fenwick.get(2), 0.75);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::fenwick_2d::*;
+use competitive_programming::mod_int::*;
+
+struct Mod1e9_7;
+impl Modulo for Mod1e9_7 {
+    type T = u32;
+    type OpT = u64;
+    const MOD: Self::T = MODS::Mod_1e9_7 as u32;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as u32
+    }
+}
+
+#[test]
+fn rectangle_sums_match_brute_force() {
+    let mut rng = Rng::new(2);
+    for _ in 0..100 {
+        let (n, m) = (rng.usize(1, 10), rng.usize(1, 10));
+        let mut fenwick = FenwickTree2D::<i64>::new(n, m);
+        let mut a = vec![vec![0i64; m]; n];
+        for _ in 0..100 {
+            let ((r1, r2), (c1, c2)) = (rng.segment(n), rng.segment(m));
+            if rng.bool() {
+                let x = rng.range(-100, 100);
+                fenwick.add(r1, c1, x);
+                a[r1][c1] += x;
+            } else {
+                let sum: i64 = a[r1..=r2]
+                    .iter()
+                    .map(|row| row[c1..=c2].iter().sum::<i64>())
+                    .sum();
+                assert_eq!(fenwick.query(r1..=r2, c1..c2 + 1), sum);
+                let prefix: i64 = a[..=r2]
+                    .iter()
+                    .map(|row| row[..=c2].iter().sum::<i64>())
+                    .sum();
+                assert_eq!(fenwick.get(r2, c2), prefix);
+            }
+        }
+    }
+}
+
+#[test]
+fn rectangle_add_rectangle_sum_matches_brute_force() {
+    let mut rng = Rng::new(3);
+    for _ in 0..100 {
+        let (n, m) = (rng.usize(1, 10), rng.usize(1, 10));
+        let mut fenwick = RangeFenwickTree2D::<i64>::new(n, m);
+        let mut a = vec![vec![0i64; m]; n];
+        for _ in 0..100 {
+            let ((r1, r2), (c1, c2)) = (rng.segment(n), rng.segment(m));
+            if rng.bool() {
+                let x = rng.range(-100, 100);
+                fenwick.add(r1..=r2, c1..=c2, x);
+                a[r1..=r2]
+                    .iter_mut()
+                    .for_each(|row| row[c1..=c2].iter_mut().for_each(|y| *y += x));
+            } else {
+                let sum: i64 = a[r1..=r2]
+                    .iter()
+                    .map(|row| row[c1..=c2].iter().sum::<i64>())
+                    .sum();
+                assert_eq!(fenwick.query(r1..=r2, c1..=c2), sum);
+            }
+        }
+        let total: i64 = a.iter().flatten().sum();
+        assert_eq!(fenwick.query(.., ..), total);
+    }
+}
+
+#[test]
+fn rectangle_add_with_mod_int() {
+    type Mint = ModInt<Mod1e9_7>;
+    let mut fenwick = RangeFenwickTree2D::<Mint>::new(3, 4);
+    fenwick.add(0..2, 1..=3, Mint::new(1_000_000_006u32));
+    fenwick.add(1.., .., Mint::new(2u32));
+    // Every cell of the rows 0 and 1, columns 1..=3 has -1, and the rows 1 and 2 have +2
+    assert_eq!(fenwick.query(.., ..), Mint::new(16u32) - Mint::new(6u32));
+    assert_eq!(fenwick.query(0..=0, ..), -Mint::new(3u32));
+    assert_eq!(fenwick.query(1..=1, 0..=0), Mint::new(2u32));
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::fraction::*;
+use competitive_programming::math::custom_math_traits::*;
+use competitive_programming::math::gcd;
+
+type Frac = Fraction<i64, i128>;
+
+/// The fraction `num / den` should be in its lowest terms, with the same value as `(n, d)`.
+fn assert_value(f: Frac, n: i128, d: i128) {
+    assert_eq!(f.num as i128 * d, f.den as i128 * n, "{} != {}/{}", f, n, d);
+    assert_eq!(gcd(f.num, f.den).abs(), 1, "{} is not reduced", f);
+}
+
+#[test]
+fn arithmetic_matches_cross_multiplication() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1000 {
+        let (a, b) = (rng.range(-20, 20), rng.range(1, 20));
+        let (c, d) = (rng.range(-20, 20), rng.range(1, 20));
+        let (x, y) = (Frac::new(a, b), Frac::new(c, d));
+        let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+
+        assert_value(x, a, b);
+        assert_value(x + y, a * d + b * c, b * d);
+        assert_value(x - y, a * d - b * c, b * d);
+        assert_value(x * y, a * c, b * d);
+        if c != 0 {
+            assert_value(x / y, a * d, b * c);
+        }
+        assert_eq!(x.cmp(&y), (a * d).cmp(&(b * c)));
+        assert_eq!(x == y, a * d == b * c);
+    }
+}
+
+#[test]
+fn identities() {
+    assert_eq!(Frac::default(), Frac::new(0, 5));
+    assert_eq!(Frac::one(), Frac::new(3, 3));
+    assert_eq!(Frac::new(2, 6).mul_inv(), Frac::new(3, 1));
+    assert_eq!(format!("{}", Frac::new(6, 4)), "3/2");
+}
+
+#[test]
+fn negative_denominators_are_normalized() {
+    let half = Frac::new(-1, -2);
+    assert_eq!((half.num, half.den), (1, 2));
+    let minus_half = Frac::new(3, -6);
+    assert_eq!((minus_half.num, minus_half.den), (-1, 2));
+    assert_eq!(minus_half, Frac::new(-1, 2));
+    assert!(minus_half < Frac::default() && Frac::default() < half);
+    assert!(Frac::new(1, -3) > Frac::new(2, -3));
+}
+
+#[test]
+#[should_panic(expected = "Denominator cannot be zero!")]
+fn zero_denominator() {
+    Frac::new(1, 0);
+}
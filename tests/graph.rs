@@ -0,0 +1,70 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::graph::*;
+
+#[test]
+fn undirected_dfs_classifies_tree_and_back_edges() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 30);
+        let extra = rng.usize(0, n);
+        let mut g = Graph::<i32, false>::new(n, n - 1 + extra);
+        for (v, p) in rng.tree_parents(n).into_iter().enumerate().skip(1) {
+            g.add_edge(p, v, 1);
+        }
+        for _ in 0..extra {
+            let (u, v) = (rng.usize(0, n - 1), rng.usize(0, n - 1));
+            g.add_edge(u, v, 2);
+        }
+
+        g.dfs(0, None);
+        assert!(g
+            .node_visit_status
+            .iter()
+            .all(|&s| s == NodeVisitStatus::Visited));
+        let tree_edges = g
+            .edge_type
+            .iter()
+            .filter(|&&t| t == EdgeType::TreeEdge)
+            .count();
+        let back_edges = g
+            .edge_type
+            .iter()
+            .filter(|&&t| t == EdgeType::BackEdge)
+            .count();
+        assert_eq!(tree_edges, n - 1);
+        assert_eq!(back_edges, extra);
+    }
+}
+
+#[test]
+fn directed_dfs_finds_cycles() {
+    let mut rng = Rng::new(2);
+    for _ in 0..200 {
+        let n = rng.usize(1, 8);
+        let m = rng.usize(0, 12);
+        let mut g = Graph::<(), true>::new(n, m);
+        let mut reach = vec![vec![false; n]; n];
+        for _ in 0..m {
+            let (u, v) = (rng.usize(0, n - 1), rng.usize(0, n - 1));
+            g.add_edge(u, v, ());
+            reach[u][v] = true;
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    reach[i][j] |= reach[i][k] && reach[k][j];
+                }
+            }
+        }
+
+        for u in 0..n {
+            if g.node_visit_status[u] == NodeVisitStatus::NotVisited {
+                g.dfs(u, None);
+            }
+        }
+        let has_back_edge = g.edge_type.contains(&EdgeType::BackEdge);
+        assert_eq!(has_back_edge, (0..n).any(|u| reach[u][u]));
+    }
+}
@@ -0,0 +1,32 @@
+use competitive_programming::grid::*;
+use std::collections::HashSet;
+
+#[test]
+fn bounds() {
+    assert!(check_grid_bound(0, 0, 1, 1));
+    assert!(check_grid_bound(2, 4, 3, 5));
+    assert!(!check_grid_bound(-1, 0, 3, 5));
+    assert!(!check_grid_bound(0, 5, 3, 5));
+    assert!(!check_grid_bound(3, 0, 3, 5));
+}
+
+#[test]
+fn directions_are_distinct() {
+    let grid4: HashSet<(isize, isize)> = DIR_GRID4.iter().map(|&(_, di, dj)| (di, dj)).collect();
+    let grid8: HashSet<(isize, isize)> = DIR_GRID8.iter().copied().collect();
+    let diagonal: HashSet<(isize, isize)> = DIR_GRID_DIAGONAL.iter().copied().collect();
+    let knight: HashSet<(isize, isize)> = DIR_CHESS_KNIGHT.iter().copied().collect();
+
+    assert_eq!(grid4.len(), 4);
+    assert_eq!(grid8.len(), 8);
+    assert_eq!(knight.len(), 8);
+    assert!(grid4.iter().all(|&(di, dj)| di.abs() + dj.abs() == 1));
+    assert!(diagonal
+        .iter()
+        .all(|&(di, dj)| di.abs() == 1 && dj.abs() == 1));
+    assert!(knight.iter().all(|&(di, dj)| di.abs() * dj.abs() == 2));
+    assert_eq!(grid8, grid4.union(&diagonal).copied().collect());
+
+    let letters: HashSet<u8> = DIR_GRID4.iter().map(|&(c, _, _)| c).collect();
+    assert_eq!(letters, HashSet::from([b'D', b'R', b'U', b'L']));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::interval_container::*;
+
+/// The maximal runs of `true`.
+fn intervals(covered: &[bool]) -> Vec<(usize, usize)> {
+    let mut ans = vec![];
+    let mut i = 0;
+    while i < covered.len() {
+        if covered[i] {
+            let j = (i..covered.len())
+                .take_while(|&j| covered[j])
+                .last()
+                .unwrap();
+            ans.push((i, j));
+            i = j + 1;
+        } else {
+            i += 1;
+        }
+    }
+    ans
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..200 {
+        let n = rng.usize(1, 40);
+        let mut container = IntervalContainer::new();
+        let mut covered = vec![false; n];
+        for _ in 0..20 {
+            let (l, r) = rng.segment(n);
+            container.add(l, r);
+            covered[l..=r].fill(true);
+
+            let expected = intervals(&covered);
+            assert_eq!(
+                container.intervals.iter().copied().collect::<Vec<_>>(),
+                expected
+            );
+            assert_eq!(container.elements, covered.iter().filter(|&&x| x).count());
+        }
+    }
+}
+
+#[test]
+fn adjacent_intervals_are_merged() {
+    let mut container = IntervalContainer::new();
+    container.add(1, 2);
+    container.add(5, 6);
+    container.add(3, 4);
+    assert_eq!(
+        container.intervals.iter().copied().collect::<Vec<_>>(),
+        [(1, 6)]
+    );
+    assert_eq!(container.elements, 6);
+}
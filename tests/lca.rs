@@ -0,0 +1,44 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::lca::*;
+
+#[test]
+fn matches_naive_climbing() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 60);
+        let par = rng.tree_parents(n);
+        let mut depth = vec![0usize; n];
+        for u in 1..n {
+            depth[u] = depth[par[u]] + 1;
+        }
+        let lifting = BinaryLifting::from_parent_and_depth(&par, &depth);
+
+        let ancestor = |mut u: usize, k: usize| {
+            for _ in 0..k {
+                u = par[u];
+            }
+            u
+        };
+        for _ in 0..100 {
+            let (mut a, mut b) = (rng.usize(0, n - 1), rng.usize(0, n - 1));
+            let k = rng.usize(0, depth[a]);
+            assert_eq!(lifting.lift(a, k as u32), ancestor(a, k));
+
+            let (u, v) = (a, b);
+            while a != b {
+                if depth[a] >= depth[b] {
+                    a = par[a];
+                } else {
+                    b = par[b];
+                }
+            }
+            assert_eq!(lifting.lca(u, v), a);
+            assert_eq!(
+                lifting.dist(u, v) as usize,
+                depth[u] + depth[v] - 2 * depth[a]
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::math::custom_math_traits::*;
+use competitive_programming::math::*;
+
+#[test]
+fn pow_matches_repeated_multiplication() {
+    let mut rng = Rng::new(1);
+    for _ in 0..200 {
+        let base = rng.range(-5, 5);
+        let exp = rng.range(0, 20) as u32;
+        let expected = (0..exp).fold(1i64, |acc, _| acc * base);
+        assert_eq!(pow(base, exp), expected);
+        assert_eq!(pow_with_identity(base, exp, 1), expected);
+    }
+    assert_eq!(pow(2.0f64, 10u32), 1024.0);
+}
+
+#[test]
+fn pow_does_not_overflow_when_the_answer_fits() {
+    assert_eq!(pow(2i64, 62u32), 1 << 62);
+    assert_eq!(pow(3u64, 40u32), 12_157_665_459_056_928_801);
+    assert_eq!(pow(-2i32, 31u32), i32::MIN);
+    assert_eq!(pow_with_identity(255u8, 1u32, 1), 255);
+}
+
+#[test]
+fn gcd_and_lcm_match_brute_force() {
+    for a in 1..60u64 {
+        for b in 1..60u64 {
+            let g = (1..=a.min(b))
+                .rev()
+                .find(|d| a % d == 0 && b % d == 0)
+                .unwrap();
+            let l = (a.max(b)..).find(|m| m % a == 0 && m % b == 0).unwrap();
+            assert_eq!(gcd(a, b), g);
+            assert_eq!(lcm(a, b), l);
+        }
+    }
+    assert_eq!(gcd(0u32, 7), 7);
+    assert_eq!(gcd(7u32, 0), 7);
+}
+
+#[test]
+fn midpoints_do_not_overflow() {
+    assert_eq!(<u8 as Midpoint>::midpoint(255, 253), 254);
+    assert_eq!(<i8 as Midpoint>::midpoint(-128, 127), 0);
+    assert_eq!(
+        <u64 as RoundedMidpoint>::midpoint_ceil(u64::MAX, u64::MAX - 1),
+        u64::MAX
+    );
+    assert_eq!(
+        <i64 as RoundedMidpoint>::midpoint_floor(i64::MIN, i64::MIN + 1),
+        i64::MIN
+    );
+}
+
+#[test]
+fn midpoints_match_wide_arithmetic() {
+    for a in -50i32..50 {
+        for b in -50i32..50 {
+            let sum = a + b;
+            // Signed midpoint rounds towards zero
+            assert_eq!(<i32 as Midpoint>::midpoint(a, b), sum / 2);
+            assert_eq!(
+                <i32 as RoundedMidpoint>::midpoint_floor(a, b),
+                sum.div_euclid(2)
+            );
+            assert_eq!(
+                <i32 as RoundedMidpoint>::midpoint_ceil(a, b),
+                -(-sum).div_euclid(2)
+            );
+        }
+    }
+    for a in 0u32..100 {
+        for b in 0u32..100 {
+            assert_eq!(<u32 as Midpoint>::midpoint(a, b), (a + b) / 2);
+            assert_eq!(
+                <u32 as RoundedMidpoint>::midpoint_ceil(a, b),
+                (a + b + 1) / 2
+            );
+        }
+    }
+}
+
+#[test]
+fn identity_and_inverse() {
+    assert_eq!(<i64 as MultiplicativeIdentity>::one(), 1);
+    assert_eq!(4u32.mul_inv(), 0.25f64);
+    assert_eq!(0.5f32.mul_inv(), 2f32);
+}
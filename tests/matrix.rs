@@ -0,0 +1,70 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::matrix::*;
+
+fn random_matrix(rng: &mut Rng, n: usize, m: usize) -> Matrix<i64> {
+    let mut mat = Matrix::zero(n, m);
+    for row in mat.mat.iter_mut() {
+        *row = rng.vec(m, -5, 5);
+    }
+    mat
+}
+
+#[test]
+fn fibonacci() {
+    let mut q = Matrix::<u64>::zero(2, 2);
+    q.mat = vec![vec![1, 1], vec![1, 0]];
+    let mut fib = vec![0u64, 1];
+    for i in 2..=90 {
+        fib.push(fib[i - 1] + fib[i - 2]);
+    }
+    for k in 0..90u32 {
+        assert_eq!(q.pow(k).mat[0][1], fib[k as usize]);
+    }
+}
+
+#[test]
+fn operations_match_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let (n, m, l) = (rng.usize(1, 5), rng.usize(1, 5), rng.usize(1, 5));
+        let a = random_matrix(&mut rng, n, m);
+        let b = random_matrix(&mut rng, n, m);
+        let c = random_matrix(&mut rng, m, l);
+
+        let (sum, diff, neg, prod) = (&a + &b, &a - &b, -&a, &a * &c);
+        for i in 0..n {
+            for j in 0..m {
+                assert_eq!(sum.mat[i][j], a.mat[i][j] + b.mat[i][j]);
+                assert_eq!(diff.mat[i][j], a.mat[i][j] - b.mat[i][j]);
+                assert_eq!(neg.mat[i][j], -a.mat[i][j]);
+            }
+            for j in 0..l {
+                let expected: i64 = (0..m).map(|k| a.mat[i][k] * c.mat[k][j]).sum();
+                assert_eq!(prod.mat[i][j], expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn pow_matches_repeated_multiplication() {
+    let mut rng = Rng::new(2);
+    for _ in 0..50 {
+        let n = rng.usize(1, 4);
+        let a = random_matrix(&mut rng, n, n);
+        let mut expected = Matrix::<i64>::identity(n);
+        for k in 0..8u32 {
+            assert_eq!(a.pow(k).mat, expected.mat);
+            expected *= &a;
+        }
+    }
+}
+
+#[test]
+fn pow_does_not_overflow_when_the_answer_fits() {
+    let mut a = Matrix::<i64>::identity(2);
+    a.mat[0][0] = 2;
+    assert_eq!(a.pow(62u32).mat, [[1 << 62, 0], [0, 1]]);
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::math::custom_math_traits::*;
+use competitive_programming::mod_int::*;
+
+struct Mod1e9_7;
+impl Modulo for Mod1e9_7 {
+    type T = u32;
+    type OpT = u64;
+    const MOD: Self::T = MODS::Mod_1e9_7 as u32;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as u32
+    }
+}
+
+struct Mod13;
+impl Modulo for Mod13 {
+    type T = u32;
+    type OpT = u64;
+    const MOD: Self::T = 13;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as u32
+    }
+}
+
+/// A signed representation, to check the normalization of negative values.
+struct SignedMod13;
+impl Modulo for SignedMod13 {
+    type T = i64;
+    type OpT = i128;
+    const MOD: Self::T = 13;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as i64
+    }
+}
+
+type Mint = ModInt<Mod1e9_7>;
+const MOD: u64 = 1_000_000_007;
+
+#[test]
+fn arithmetic_matches_u128() {
+    let mut rng = Rng::new(1);
+    for _ in 0..10000 {
+        let (a, b) = (rng.next_u64() % MOD, rng.next_u64() % MOD);
+        let (x, y) = (Mint::new(a), Mint::new(b));
+        assert_eq!((x + y).0 as u64, (a + b) % MOD);
+        assert_eq!((x - y).0 as u64, (a + MOD - b) % MOD);
+        assert_eq!(
+            (x * y).0 as u64,
+            (a as u128 * b as u128 % MOD as u128) as u64
+        );
+        assert_eq!((-x).0 as u64, (MOD - a) % MOD);
+        if b != 0 {
+            assert_eq!((x / y) * y, x);
+            assert_eq!(y.mul_inv() * y, Mint::one());
+        }
+    }
+}
+
+#[test]
+fn normalization() {
+    assert_eq!(Mint::new(MOD * 3 + 5).0, 5);
+    assert_eq!(ModInt::<SignedMod13>::new(-5i64).0, 8);
+    assert_eq!(ModInt::<SignedMod13>::new(-26i64).0, 0);
+    assert_eq!(
+        format!("{} {:?}", Mint::new(7u32), Mint::new(7u32)),
+        "7 Mint(7)"
+    );
+}
+
+#[test]
+fn inverses_in_a_small_field() {
+    for a in 1..13u32 {
+        let x = ModInt::<Mod13>::new(a);
+        let inv = (1..13u32).find(|b| a * b % 13 == 1).unwrap();
+        assert_eq!(x.mul_inv().0, inv);
+    }
+}
+
+#[test]
+fn combinatorics_match_pascals_triangle() {
+    const N: usize = 60;
+    let comb = Combinatorics::<Mod1e9_7, N>::new();
+    let mut pascal = vec![vec![0u64; N]; N];
+    for n in 0..N {
+        pascal[n][0] = 1;
+        for r in 1..=n {
+            pascal[n][r] = (pascal[n - 1][r - 1] + pascal[n - 1][r]) % MOD;
+        }
+    }
+
+    let mut fact = 1u64;
+    for (n, row) in pascal.iter().enumerate() {
+        if n > 0 {
+            fact = fact * n as u64 % MOD;
+            assert_eq!(comb.inv(n) * Mint::new(n as u64), Mint::one());
+        }
+        assert_eq!(comb.fact(n).0 as u64, fact);
+        assert_eq!(comb.fact(n) * comb.inv_fact(n), Mint::one());
+        for (r, &expected) in row.iter().enumerate() {
+            assert_eq!(comb.c(n, r).0 as u64, expected);
+            assert_eq!(c_linear::<usize, Mod1e9_7>(n, r).0 as u64, expected);
+            let p = if r > n {
+                0
+            } else {
+                (n - r + 1..=n).fold(1, |p, x| p * x as u64 % MOD)
+            };
+            assert_eq!(comb.p(n, r).0 as u64, p);
+        }
+    }
+    assert_eq!(comb.c(-1i64, 0), Mint::default());
+}
+
+#[test]
+fn powers() {
+    let b = Mint::new(31u32);
+    let pow = get_powers(b, 20);
+    let inv_pow = get_inv_powers(b, 20);
+    let mut expected = Mint::one();
+    for i in 0..=20 {
+        assert_eq!(pow[i], expected);
+        assert_eq!(pow[i] * inv_pow[i], Mint::one());
+        expected *= b;
+    }
+}
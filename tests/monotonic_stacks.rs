@@ -0,0 +1,43 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::monotonic_stacks::*;
+
+fn brute_next(a: &[i64], cmp: impl Fn(i64, i64) -> bool) -> Vec<usize> {
+    (0..a.len())
+        .map(|i| {
+            (i + 1..a.len())
+                .find(|&j| cmp(a[i], a[j]))
+                .unwrap_or(a.len())
+        })
+        .collect()
+}
+
+fn brute_prev(a: &[i64], cmp: impl Fn(i64, i64) -> bool) -> Vec<isize> {
+    (0..a.len())
+        .map(|i| {
+            (0..i)
+                .rev()
+                .find(|&j| cmp(a[i], a[j]))
+                .map_or(-1, |j| j as isize)
+        })
+        .collect()
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..300 {
+        let n = rng.usize(1, 30);
+        let a = rng.vec(n, 0, 5);
+
+        assert_eq!(next_greater(&a), brute_next(&a, |x, y| y > x));
+        assert_eq!(next_greater_equal(&a), brute_next(&a, |x, y| y >= x));
+        assert_eq!(next_smaller(&a), brute_next(&a, |x, y| y < x));
+        assert_eq!(next_smaller_equal(&a), brute_next(&a, |x, y| y <= x));
+        assert_eq!(prev_greater(&a), brute_prev(&a, |x, y| y > x));
+        assert_eq!(prev_greater_equal(&a), brute_prev(&a, |x, y| y >= x));
+        assert_eq!(prev_smaller(&a), brute_prev(&a, |x, y| y < x));
+        assert_eq!(prev_smaller_equal(&a), brute_prev(&a, |x, y| y <= x));
+    }
+}
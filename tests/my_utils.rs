@@ -0,0 +1,31 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::my_utils::*;
+
+#[test]
+fn inclusive_bounds() {
+    assert_eq!(get_inclusive_usize_bounds(&(2..5), 10), (2, 4));
+    assert_eq!(get_inclusive_usize_bounds(&(2..=5), 10), (2, 5));
+    assert_eq!(get_inclusive_usize_bounds(&(..5), 10), (0, 4));
+    assert_eq!(get_inclusive_usize_bounds(&(3..), 10), (3, 9));
+    assert_eq!(get_inclusive_usize_bounds(&(..), 10), (0, 9));
+}
+
+#[test]
+fn coordinate_compression_matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..200 {
+        let n = rng.usize(1, 30);
+        let a = rng.vec(n, -10, 10);
+        let (comp, values) = coordinate_compression(&a);
+
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        for i in 0..n {
+            assert_eq!(values[comp[i] as usize], a[i]);
+            for j in 0..n {
+                assert_eq!(comp[i].cmp(&comp[j]), a[i].cmp(&a[j]));
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::persistent_seg_tree::*;
+use competitive_programming::seg_tree_ops::{Min, Sum};
+
+#[test]
+fn versions_match_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 30);
+        let initial = rng.vec(n, -10, 10);
+        let mut versions = vec![initial.clone()];
+        let mut sum = PersistentSegmentTree::<Sum<i64>>::with_nodes(&initial);
+        let mut min = PersistentSegmentTree::<Min<i64>>::with_func(n, |i| initial[i]);
+        for _ in 0..100 {
+            let version = rng.usize(0, versions.len() - 1);
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                let x = rng.range(-10, 10);
+                let mut a = versions[version].clone();
+                a[l] += x;
+                versions.push(a);
+                assert_eq!(sum.update(version, l, |c| c + x), versions.len() - 1);
+                assert_eq!(min.update(version, l, |c| c + x), versions.len() - 1);
+            } else {
+                let a = &versions[version];
+                assert_eq!(sum.query(version, l..=r), a[l..=r].iter().sum::<i64>());
+                assert_eq!(
+                    min.query(version, l..r + 1),
+                    *a[l..=r].iter().min().unwrap()
+                );
+            }
+        }
+        assert_eq!(sum.version_count(), versions.len());
+        for (version, a) in versions.iter().enumerate() {
+            assert_eq!(sum.query(version, ..), a.iter().sum::<i64>());
+            assert_eq!(sum.query(version, 1..1), 0);
+        }
+    }
+}
+
+#[test]
+fn defaults_share_the_identity_node() {
+    let n = 1_000_000_000;
+    let mut tree = PersistentSegmentTree::<Sum<i64>>::with_defaults(n);
+    assert_eq!(tree.node_count(), 1);
+    let v1 = tree.update(0, 5, |c| c + 3);
+    let v2 = tree.update(v1, n - 1, |c| c + 4);
+    assert_eq!(tree.query(0, ..), 0);
+    assert_eq!(tree.query(v1, ..), 3);
+    assert_eq!(tree.query(v2, 6..), 4);
+    assert_eq!(tree.query(v2, ..), 7);
+    // Each update creates one node per level
+    assert!(tree.node_count() <= 1 + 2 * 31);
+}
+
+#[test]
+fn empty_tree_gives_the_identity() {
+    let tree = PersistentSegmentTree::<Min<i64>>::with_nodes(&vec![]);
+    assert_eq!(tree.query(0, ..), i64::MAX);
+    assert_eq!(tree.query(0, 0..0), i64::MAX);
+    assert_eq!(tree.kth(0, 0, 0, |_, _| 0), None);
+}
+
+#[test]
+fn kth_smallest_in_range() {
+    let mut rng = Rng::new(2);
+    for _ in 0..50 {
+        let n = rng.usize(1, 50);
+        let a = rng.vec(n, -20, 20);
+        let mut values = a.clone();
+        values.sort();
+        values.dedup();
+
+        // Version `i` has the counts of the (compressed) values of `a[..i]`
+        let mut tree = PersistentSegmentTree::<Sum<i64>>::with_defaults(values.len());
+        for (i, x) in a.iter().enumerate() {
+            let pos = values.binary_search(x).unwrap();
+            assert_eq!(tree.update(i, pos, |c| c + 1), i + 1);
+        }
+
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            let mut sorted = a[l..=r].to_vec();
+            sorted.sort();
+            let k = rng.usize(0, sorted.len());
+            let found = tree.kth(l, r + 1, k, |old, new| (new - old) as usize);
+            assert_eq!(found.map(|pos| values[pos]), sorted.get(k).copied());
+        }
+    }
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::mod_int::*;
+use competitive_programming::polynomial_hash::*;
+
+struct Mod1e9_7;
+impl Modulo for Mod1e9_7 {
+    type T = u32;
+    type OpT = u64;
+    const MOD: Self::T = MODS::Mod_1e9_7 as u32;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as u32
+    }
+}
+
+struct Mod998;
+impl Modulo for Mod998 {
+    type T = u32;
+    type OpT = u64;
+    const MOD: Self::T = MODS::Mod_998 as u32;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as u32
+    }
+}
+
+#[test]
+fn equal_substrings_have_equal_hashes() {
+    let mut rng = Rng::new(1);
+    let b1 = ModInt::<Mod1e9_7>::new(131u32);
+    let b2 = ModInt::<Mod998>::new(137u32);
+    let inv_pow_b1 = get_inv_powers(b1, 100);
+    let inv_pow_b2 = get_inv_powers(b2, 100);
+    for _ in 0..50 {
+        let n = rng.usize(1, 40);
+        let s: Vec<u8> = (0..n).map(|_| b'a' + rng.usize(0, 1) as u8).collect();
+        let single = PolynomialHash::new(&s, b1, &inv_pow_b1);
+        let double = DoubleHash::new(&s, b1, &inv_pow_b1, b2, &inv_pow_b2);
+        for _ in 0..100 {
+            let (l1, r1) = rng.segment(n);
+            let len = r1 - l1 + 1;
+            let l2 = rng.usize(0, n - len);
+            let r2 = l2 + len - 1;
+            let equal = s[l1..=r1] == s[l2..=r2];
+            assert_eq!(single.get_hash(l1, r1) == single.get_hash(l2, r2), equal);
+            assert_eq!(double.get_hash(l1, r1) == double.get_hash(l2, r2), equal);
+        }
+    }
+}
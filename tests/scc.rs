@@ -0,0 +1,56 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::graph::*;
+use competitive_programming::scc::*;
+
+#[test]
+fn matches_transitive_closure() {
+    let mut rng = Rng::new(1);
+    for _ in 0..200 {
+        let n = rng.usize(1, 10);
+        let m = rng.usize(0, 20);
+        let mut g = Graph::<u8, true>::new(n, m);
+        let mut reach = vec![vec![false; n]; n];
+        for (u, row) in reach.iter_mut().enumerate() {
+            row[u] = true;
+        }
+        for _ in 0..m {
+            let (u, v) = (rng.usize(0, n - 1), rng.usize(0, n - 1));
+            g.add_edge(u, v, 0);
+            reach[u][v] = true;
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    reach[i][j] |= reach[i][k] && reach[k][j];
+                }
+            }
+        }
+
+        let scc = SCC::from_graph(&g);
+        for (u, row) in reach.iter().enumerate() {
+            for (v, &u_to_v) in row.iter().enumerate() {
+                assert_eq!(scc.group[u] == scc.group[v], u_to_v && reach[v][u]);
+            }
+            assert!(scc.components[scc.group[u] as usize].contains(&(u as u32)));
+        }
+        assert_eq!(scc.components.iter().map(|c| c.len()).sum::<usize>(), n);
+        // The components are in topological order
+        for e in &g.edges {
+            assert!(scc.group[e.u as usize] <= scc.group[e.v as usize]);
+        }
+    }
+}
+
+#[test]
+fn works_with_any_weight_type() {
+    let mut g = Graph::<String, true>::new(3, 3);
+    g.add_edge(0, 1, "a".to_string());
+    g.add_edge(1, 0, "b".to_string());
+    g.add_edge(1, 2, "c".to_string());
+    let scc = SCC::from_graph(&g);
+    assert_eq!(scc.n, 3);
+    assert_eq!(scc.group[0], scc.group[1]);
+    assert!(scc.group[1] < scc.group[2]);
+}
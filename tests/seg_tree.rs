@@ -0,0 +1,401 @@
+mod common;
+
+use common::{AddSum, Rng};
+use competitive_programming::seg_tree::*;
+use competitive_programming::seg_tree_ops::{AddMax, Max, Sum};
+
+#[test]
+fn point_update_range_query() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, -100, 100);
+        let mut sum = SegmentTree::<Sum<i64>>::with_nodes(&a);
+        let mut max = SegmentTree::<Max<i64>>::with_func(n, |i| a[i]);
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                let x = rng.range(-100, 100);
+                a[l] += x;
+                sum.update(l, |c| c + x);
+                max.update(l, |c| c + x);
+            } else {
+                assert_eq!(sum.query(l..=r), a[l..=r].iter().sum::<i64>());
+                assert_eq!(max.query(l..r + 1), *a[l..=r].iter().max().unwrap());
+            }
+        }
+        assert_eq!(sum.query(..), a.iter().sum::<i64>());
+        assert_eq!(max.query(..n), *a.iter().max().unwrap());
+    }
+}
+
+#[test]
+fn query2_visits_disjoint_nodes_covering_the_range() {
+    let mut rng = Rng::new(2);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let a = rng.vec(n, -100, 100);
+        let sum = SegmentTree::<Sum<i64>>::with_nodes(&a);
+        let mut lazy = LazySegmentTree::<AddSum>::with_nodes(a.clone());
+        let (l, r) = rng.segment(n);
+
+        let (mut total, mut count) = (0, 0);
+        sum.query2(l..=r, |x| {
+            total += x;
+            count += 1;
+        });
+        assert_eq!(total, a[l..=r].iter().sum::<i64>());
+        assert!(count <= 2 * (usize::BITS - n.leading_zeros()) as usize);
+
+        let mut total = 0;
+        lazy.query2(l..=r, |x| total += x);
+        assert_eq!(total, a[l..=r].iter().sum::<i64>());
+    }
+}
+
+#[test]
+fn iter_nodes_covers_the_range_from_left_to_right() {
+    let mut rng = Rng::new(5);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, -100, 100);
+        let sum = SegmentTree::<Sum<i64>>::with_nodes(&a);
+        let mut lazy = LazySegmentTree::<AddSum>::with_nodes(a.clone());
+        for _ in 0..20 {
+            let (l, r) = rng.segment(n);
+            let x = rng.range(-100, 100);
+            a[l..=r].iter_mut().for_each(|y| *y += x);
+            lazy.update(l..=r, x);
+        }
+        let updated = SegmentTree::<Sum<i64>>::with_nodes(&a);
+
+        for _ in 0..20 {
+            let (l, r) = rng.segment(n);
+            let nodes: Vec<(usize, usize, i64)> = lazy
+                .iter_nodes(l..=r)
+                .map(|(l, r, &data)| (l, r, data))
+                .collect();
+            assert!(nodes.len() <= 2 * (usize::BITS - n.leading_zeros()) as usize);
+            assert_eq!(nodes.first().unwrap().0, l);
+            assert_eq!(nodes.last().unwrap().1, r);
+            for i in 0..nodes.len() {
+                let (nl, nr, data) = nodes[i];
+                assert!(nl <= nr);
+                assert_eq!(data, a[nl..=nr].iter().sum::<i64>());
+                if i > 0 {
+                    assert_eq!(nodes[i - 1].1 + 1, nl);
+                }
+            }
+
+            let plain: Vec<(usize, usize, i64)> = updated
+                .iter_nodes(l..=r)
+                .map(|(l, r, &data)| (l, r, data))
+                .collect();
+            assert_eq!(plain, nodes);
+            assert!(sum
+                .iter_nodes(l..=r)
+                .map(|(l, r, _)| (l, r))
+                .eq(plain.iter().map(|x| (x.0, x.1))));
+        }
+        assert_eq!(sum.iter_nodes(1..1).count(), 0);
+    }
+}
+
+#[test]
+fn walks_match_brute_force() {
+    let mut rng = Rng::new(3);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, 0, 50);
+        let mut max = SegmentTree::<Max<i64>>::with_nodes(&a);
+        let mut lazy = LazySegmentTree::<AddMax<i64>>::with_nodes(a.clone());
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            match rng.usize(0, 2) {
+                0 => {
+                    let x = rng.range(0, 50);
+                    let current = lazy.query(l..=l);
+                    lazy.update(l..=l, x - current);
+                    a[l] = x;
+                    max.update(l, |_| x);
+                }
+                1 => {
+                    let x = rng.range(-10, 10);
+                    a[l..=r].iter_mut().for_each(|y| *y += x);
+                    lazy.update(l..=r, x);
+                    max = SegmentTree::<Max<i64>>::with_nodes(&a);
+                }
+                _ => {
+                    let x = rng.range(-10, 60);
+                    let first = (l..=r).find(|&i| a[i] >= x);
+                    let last = (l..=r).rev().find(|&i| a[i] >= x);
+                    assert_eq!(max.first_true(l..=r, |&m| m >= x), first);
+                    assert_eq!(max.last_true(l..=r, |&m| m >= x), last);
+                    assert_eq!(lazy.first_true(l..=r, |&m| m >= x), first);
+                    assert_eq!(lazy.last_true(l..=r, |&m| m >= x), last);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn lazy_range_update_range_query() {
+    let mut rng = Rng::new(4);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, -100, 100);
+        let mut sum = LazySegmentTree::<AddSum>::with_nodes(a.clone());
+        let mut max = LazySegmentTree::<AddMax<i64>>::with_func(n, |i| a[i]);
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                let x = rng.range(-100, 100);
+                a[l..=r].iter_mut().for_each(|y| *y += x);
+                sum.update(l..=r, x);
+                max.update(l..r + 1, x);
+            } else {
+                assert_eq!(sum.query(l..=r), a[l..=r].iter().sum::<i64>());
+                assert_eq!(max.query(l..=r), *a[l..=r].iter().max().unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn iterative_lazy_matches_brute_force() {
+    let mut rng = Rng::new(6);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, -100, 100);
+        let mut sum = IterativeLazySegmentTree::<AddSum>::with_nodes(a.clone());
+        let mut max = IterativeLazySegmentTree::<AddMax<i64>>::with_func(n, |i| a[i]);
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                let x = rng.range(-100, 100);
+                a[l..=r].iter_mut().for_each(|y| *y += x);
+                sum.update(l..=r, x);
+                max.update(l..r + 1, x);
+            } else {
+                assert_eq!(sum.query(l..=r), a[l..=r].iter().sum::<i64>());
+                assert_eq!(max.query(l..=r), *a[l..=r].iter().max().unwrap());
+            }
+        }
+        sum.update(.., 1);
+        a.iter_mut().for_each(|y| *y += 1);
+        assert_eq!(sum.query(..), a.iter().sum::<i64>());
+        let m = n / 2;
+        assert_eq!(sum.query(m..m), 0);
+    }
+}
+
+#[test]
+fn iterative_lazy_walks_match_brute_force() {
+    let mut rng = Rng::new(7);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, 0, 50);
+        let mut max = IterativeLazySegmentTree::<AddMax<i64>>::with_nodes(a.clone());
+        for _ in 0..100 {
+            if rng.bool() {
+                let (l, r) = rng.segment(n);
+                let x = rng.range(-10, 10);
+                a[l..=r].iter_mut().for_each(|y| *y += x);
+                max.update(l..=r, x);
+            } else {
+                let x = rng.range(-10, 60);
+                let l = rng.usize(0, n - 1);
+                let right = (l..n)
+                    .find(|&i| a[i] >= x)
+                    .unwrap_or(n)
+                    .checked_sub(1)
+                    .filter(|&r| r >= l);
+                assert_eq!(max.max_right(l, |&m| m < x), right);
+
+                let r = rng.usize(0, n - 1);
+                let left = (0..=r).rev().find(|&i| a[i] >= x).map_or(0, |i| i + 1);
+                assert_eq!(max.min_left(r, |&m| m < x), Some(left).filter(|&l| l <= r));
+            }
+        }
+    }
+}
+
+#[test]
+fn accumulated_walks_match_brute_force() {
+    let mut rng = Rng::new(8);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, 0, 10);
+        let mut sum = SegmentTree::<Sum<i64>>::with_nodes(&a);
+        let mut lazy_sum = LazySegmentTree::<AddSum>::with_nodes(a.clone());
+        let mut max = LazySegmentTree::<AddMax<i64>>::with_nodes(a.clone());
+        for _ in 0..100 {
+            match rng.usize(0, 2) {
+                0 => {
+                    let i = rng.usize(0, n - 1);
+                    a[i] = rng.range(0, 10);
+                    sum.set(i, a[i]);
+                    lazy_sum.set(i, a[i]);
+                    max.set(i, a[i]);
+                }
+                1 => {
+                    let (l, r) = rng.segment(n);
+                    let x = rng.range(0, 5);
+                    a[l..=r].iter_mut().for_each(|y| *y += x);
+                    lazy_sum.update(l..=r, x);
+                    max.update(l..=r, x);
+                    for (i, &y) in a.iter().enumerate().take(r + 1).skip(l) {
+                        sum.set(i, y);
+                    }
+                }
+                _ => {
+                    // The prefix sums are non-decreasing, so `sum <= x` is monotone
+                    let x = rng.range(0, 100);
+                    let l = rng.usize(0, n - 1);
+                    let right = (l..n).rev().find(|&r| a[l..=r].iter().sum::<i64>() <= x);
+                    assert_eq!(sum.max_right(l, |&s| s <= x), right);
+                    assert_eq!(lazy_sum.max_right(l, |&s| s <= x), right);
+
+                    let r = rng.usize(0, n - 1);
+                    let left = (0..=r).find(|&l| a[l..=r].iter().sum::<i64>() <= x);
+                    assert_eq!(sum.min_left(r, |&s| s <= x), left);
+                    assert_eq!(lazy_sum.min_left(r, |&s| s <= x), left);
+
+                    let right = (l..n)
+                        .find(|&i| a[i] >= x / 10)
+                        .unwrap_or(n)
+                        .checked_sub(1)
+                        .filter(|&r| r >= l);
+                    assert_eq!(max.max_right(l, |&m| m < x / 10), right);
+                    let left = (0..=r).rev().find(|&i| a[i] >= x / 10).map_or(0, |i| i + 1);
+                    assert_eq!(
+                        max.min_left(r, |&m| m < x / 10),
+                        Some(left).filter(|&l| l <= r)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn get_set_and_to_vec_match_brute_force() {
+    let mut rng = Rng::new(8);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, -100, 100);
+        let mut sum = SegmentTree::<Sum<i64>>::with_nodes(&a);
+        let mut lazy = LazySegmentTree::<AddSum>::with_nodes(a.clone());
+        let mut iterative = IterativeLazySegmentTree::<AddSum>::with_nodes(a.clone());
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            match rng.usize(0, 3) {
+                0 => {
+                    let x = rng.range(-100, 100);
+                    a[l..=r].iter_mut().for_each(|y| *y += x);
+                    lazy.update(l..=r, x);
+                    iterative.update(l..=r, x);
+                    sum = SegmentTree::<Sum<i64>>::with_nodes(&a);
+                }
+                1 => {
+                    let x = rng.range(-100, 100);
+                    a[l] = x;
+                    sum.set(l, x);
+                    lazy.set(l, x);
+                    iterative.set(l, x);
+                }
+                2 => {
+                    assert_eq!(sum.get(l), a[l]);
+                    assert_eq!(lazy.get(l), a[l]);
+                    assert_eq!(iterative.get(l), a[l]);
+                }
+                _ => {
+                    assert_eq!(sum.query(l..=r), a[l..=r].iter().sum::<i64>());
+                    assert_eq!(lazy.query(l..=r), a[l..=r].iter().sum::<i64>());
+                    assert_eq!(iterative.query(l..=r), a[l..=r].iter().sum::<i64>());
+                }
+            }
+        }
+        assert_eq!(sum.to_vec(), a);
+        assert_eq!(lazy.to_vec(), a);
+        assert_eq!(iterative.to_vec(), a);
+        // Still consistent after pushing everything
+        assert_eq!(lazy.query(..n), a.iter().sum::<i64>());
+        assert_eq!(iterative.query(..), a.iter().sum::<i64>());
+    }
+}
+
+#[test]
+fn defaults_are_identities() {
+    let tree = SegmentTree::<Sum<i64>>::with_defaults(5);
+    assert_eq!(tree.query(..), 0);
+    let mut tree = LazySegmentTree::<AddMax<i64>>::with_defaults(5);
+    tree.update(.., 3);
+    assert_eq!(tree.query(1..4), i64::MIN);
+}
+
+#[test]
+fn empty_lazy_trees_give_the_identity() {
+    let mut tree = LazySegmentTree::<AddSum>::with_nodes(vec![]);
+    tree.update(.., 3);
+    assert_eq!(tree.query(..), 0);
+    assert_eq!(tree.first_true(.., |_| true), None);
+    assert_eq!(tree.iter_nodes(..).count(), 0);
+
+    let mut tree = IterativeLazySegmentTree::<AddSum>::with_nodes(vec![]);
+    assert_eq!(tree.query(..), 0);
+    tree.update(.., 3);
+    assert_eq!(tree.query(..), 0);
+    assert!(tree.to_vec().is_empty());
+}
+
+#[test]
+fn walks_return_inclusive_indices() {
+    let a = vec![1, 2, 3, 4];
+    let tree = SegmentTree::<Sum<i64>>::with_nodes(&a);
+    let mut lazy = IterativeLazySegmentTree::<AddSum>::with_nodes(a);
+    // sum(a[0..=2]) = 6 and sum(a[2..=3]) = 7
+    assert_eq!(tree.max_right(0, |&s| s <= 6), Some(2));
+    assert_eq!(lazy.max_right(0, |&s| s <= 6), Some(2));
+    assert_eq!(tree.max_right(1, |&s| s <= 100), Some(3));
+    assert_eq!(tree.max_right(3, |&s| s < 4), None);
+    assert_eq!(tree.min_left(3, |&s| s <= 7), Some(2));
+    assert_eq!(lazy.min_left(3, |&s| s <= 7), Some(2));
+    assert_eq!(tree.min_left(2, |&s| s <= 100), Some(0));
+    assert_eq!(tree.min_left(0, |&s| s < 1), None);
+}
+
+#[test]
+#[should_panic(expected = "Index 4 out of bounds for size 4")]
+fn walk_past_the_end_panics() {
+    let tree = SegmentTree::<Sum<i64>>::with_nodes(&vec![1, 2, 3, 4]);
+    tree.max_right(4, |_| true);
+}
+
+#[test]
+fn point_access_past_n_panics() {
+    // `n = 5` has padding leaves till `8`, which shouldn't be reachable
+    let expect_panic = |access: &dyn Fn()| {
+        let error = std::panic::catch_unwind(std::panic::AssertUnwindSafe(access)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<String>().unwrap(),
+            "Index 5 out of bounds for size 5"
+        );
+    };
+    let tree = || SegmentTree::<Sum<i64>>::with_defaults(5);
+    expect_panic(&|| {
+        tree().get(5);
+    });
+    expect_panic(&|| tree().set(5, 1));
+    let lazy = || LazySegmentTree::<AddSum>::with_defaults(5);
+    expect_panic(&|| {
+        lazy().get(5);
+    });
+    expect_panic(&|| lazy().set(5, 1));
+    let iterative = || IterativeLazySegmentTree::<AddSum>::with_defaults(5);
+    expect_panic(&|| {
+        iterative().get(5);
+    });
+    expect_panic(&|| iterative().set(5, 1));
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::seg_tree_2d::*;
+use competitive_programming::seg_tree_ops::{Max, Sum};
+
+fn rectangle(a: &[Vec<i64>], (r1, r2): (usize, usize), (c1, c2): (usize, usize)) -> Vec<i64> {
+    a[r1..=r2]
+        .iter()
+        .flat_map(|row| row[c1..=c2].iter().copied())
+        .collect()
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let (n, m) = (rng.usize(1, 12), rng.usize(1, 12));
+        let mut a: Vec<Vec<i64>> = (0..n).map(|_| rng.vec(m, -20, 20)).collect();
+        let mut sum = SegmentTree2D::<Sum<i64>>::with_nodes(&a);
+        let mut max = SegmentTree2D::<Max<i64>>::with_func(n, m, |i, j| a[i][j]);
+        for _ in 0..100 {
+            let (rows, cols) = (rng.segment(n), rng.segment(m));
+            if rng.bool() {
+                let (i, j) = (rows.0, cols.0);
+                let x = rng.range(-20, 20);
+                a[i][j] += x;
+                sum.update(i, j, |c| c + x);
+                max.set(i, j, a[i][j]);
+                assert_eq!(sum.get(i, j), a[i][j]);
+            } else {
+                let cells = rectangle(&a, rows, cols);
+                assert_eq!(
+                    sum.query(rows.0..=rows.1, cols.0..=cols.1),
+                    cells.iter().sum::<i64>()
+                );
+                assert_eq!(
+                    max.query(rows.0..rows.1 + 1, cols.0..=cols.1),
+                    *cells.iter().max().unwrap()
+                );
+            }
+        }
+        let all = rectangle(&a, (0, n - 1), (0, m - 1));
+        assert_eq!(sum.query(.., ..), all.iter().sum::<i64>());
+        assert_eq!(max.query(.., ..), *all.iter().max().unwrap());
+    }
+}
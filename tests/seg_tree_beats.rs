@@ -0,0 +1,78 @@
+mod common;
+
+use common::{AddSum, Rng};
+use competitive_programming::seg_tree::*;
+use competitive_programming::seg_tree_beats::*;
+
+#[test]
+fn beats_matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 40);
+        let mut a = rng.vec(n, -20, 20);
+        let mut recursive =
+            LazySegmentTree::<ChminChmaxAddSum>::with_func(n, |i| BeatsNode::new(a[i]));
+        let mut iterative =
+            IterativeLazySegmentTree::<ChminChmaxAddSum>::with_func(n, |i| BeatsNode::new(a[i]));
+        for _ in 0..200 {
+            let (l, r) = rng.segment(n);
+            let x = rng.range(-20, 20);
+            let update = match rng.usize(0, 4) {
+                0 => {
+                    a[l..=r].iter_mut().for_each(|y| *y = (*y).min(x));
+                    BeatsUpdate::chmin(x)
+                }
+                1 => {
+                    a[l..=r].iter_mut().for_each(|y| *y = (*y).max(x));
+                    BeatsUpdate::chmax(x)
+                }
+                2 => {
+                    a[l..=r].iter_mut().for_each(|y| *y += x);
+                    BeatsUpdate::add(x)
+                }
+                _ => {
+                    for (name, node) in [
+                        ("recursive", recursive.query(l..=r)),
+                        ("iterative", iterative.query(l..=r)),
+                    ] {
+                        assert_eq!(node.sum, a[l..=r].iter().sum::<i64>(), "{}", name);
+                        assert_eq!(node.max, *a[l..=r].iter().max().unwrap(), "{}", name);
+                        assert_eq!(node.min, *a[l..=r].iter().min().unwrap(), "{}", name);
+                        assert_eq!(node.len, r - l + 1, "{}", name);
+                    }
+                    continue;
+                }
+            };
+            recursive.update(l..=r, update.clone());
+            iterative.update(l..=r, update);
+        }
+        let values = |v: Vec<BeatsNode>| v.iter().map(|node| node.sum).collect::<Vec<_>>();
+        assert_eq!(values(recursive.to_vec()), a);
+        assert_eq!(values(iterative.to_vec()), a);
+    }
+}
+
+#[test]
+fn default_try_apply_keeps_the_plain_lazy_trees() {
+    let mut rng = Rng::new(2);
+    let n = 50;
+    let mut a = rng.vec(n, -10, 10);
+    let mut tree = LazySegmentTree::<AddSum>::with_nodes(a.clone());
+    for _ in 0..500 {
+        let (l, r) = rng.segment(n);
+        if rng.bool() {
+            let x = rng.range(-10, 10);
+            a[l..=r].iter_mut().for_each(|y| *y += x);
+            tree.update(l..=r, x);
+        } else {
+            assert_eq!(tree.query(l..=r), a[l..=r].iter().sum::<i64>());
+        }
+    }
+    assert_eq!(AddSum::try_apply(&5, &2, 0, 2), Some(11));
+}
+
+#[test]
+#[should_panic(expected = "The values should be strictly between i64::MIN and i64::MAX")]
+fn chmax_to_the_sentinel_panics() {
+    BeatsUpdate::chmax(i64::MAX);
+}
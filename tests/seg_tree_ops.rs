@@ -0,0 +1,205 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::math::custom_math_traits::*;
+use competitive_programming::math::gcd;
+use competitive_programming::mod_int::*;
+use competitive_programming::seg_tree::*;
+use competitive_programming::seg_tree_ops::*;
+
+struct Mod1e9_7;
+impl Modulo for Mod1e9_7 {
+    type T = u32;
+    type OpT = u64;
+    const MOD: Self::T = MODS::Mod_1e9_7 as u32;
+
+    fn opt_to_t(val: Self::OpT) -> Self::T {
+        val as u32
+    }
+}
+
+type Mint = ModInt<Mod1e9_7>;
+
+/// Point updates (assigning random values) and range queries, against merging the elements one by one.
+fn check_monoid<Ops, G, E>(seed: u64, mut generate: G, mut eq: E)
+where
+    Ops: SegmentTreeOperations,
+    G: FnMut(&mut Rng) -> Ops::Data,
+    E: FnMut(&Ops::Data, &Ops::Data) -> bool,
+    Ops::Data: std::fmt::Debug,
+{
+    let mut rng = Rng::new(seed);
+    for _ in 0..50 {
+        let n = rng.usize(1, 30);
+        let mut a: Vec<Ops::Data> = (0..n).map(|_| generate(&mut rng)).collect();
+        let mut seg_tree = SegmentTree::<Ops>::with_nodes(&a);
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                a[l] = generate(&mut rng);
+                seg_tree.set(l, a[l].clone());
+            } else {
+                let expected = a[l..=r]
+                    .iter()
+                    .fold(Ops::data_identity(), |acc, x| Ops::merge(&acc, x));
+                let found = seg_tree.query(l..=r);
+                assert!(eq(&found, &expected), "{:?} != {:?}", found, expected);
+            }
+        }
+    }
+}
+
+/// Range updates and range queries, against applying the updates on every element.
+fn check_lazy<Ops, G, U>(seed: u64, mut generate: G, mut generate_update: U)
+where
+    Ops: LazySegmentTreeOperations,
+    G: FnMut(&mut Rng) -> Ops::Data,
+    U: FnMut(&mut Rng) -> Ops::Lazy,
+    Ops::Data: std::fmt::Debug + PartialEq,
+{
+    let mut rng = Rng::new(seed);
+    for _ in 0..50 {
+        let n = rng.usize(1, 30);
+        let mut a: Vec<Ops::Data> = (0..n).map(|_| generate(&mut rng)).collect();
+        let mut seg_tree = LazySegmentTree::<Ops>::with_nodes(a.clone());
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                let update = generate_update(&mut rng);
+                for (i, x) in a.iter_mut().enumerate().take(r + 1).skip(l) {
+                    *x = Ops::apply(x, &update, i, i);
+                }
+                // The open end shouldn't reach the padding
+                if r == n - 1 && rng.bool() {
+                    seg_tree.update(l.., update);
+                } else {
+                    seg_tree.update(l..=r, update);
+                }
+            } else {
+                let expected = a[l..=r]
+                    .iter()
+                    .fold(Ops::data_identity(), |acc, x| Ops::merge(&acc, x));
+                assert_eq!(seg_tree.query(l..=r), expected);
+            }
+        }
+        let expected = a
+            .iter()
+            .fold(Ops::data_identity(), |acc, x| Ops::merge(&acc, x));
+        assert_eq!(seg_tree.query(..), expected);
+    }
+}
+
+#[test]
+fn monoids_match_brute_force() {
+    check_monoid::<Sum<i64>, _, _>(1, |rng| rng.range(-100, 100), |a, b| a == b);
+    check_monoid::<Sum<f64>, _, _>(
+        2,
+        |rng| rng.range(-100, 100) as f64 / 8.0,
+        |a, b| (a - b).abs() < 1e-9,
+    );
+    check_monoid::<Sum<Mint>, _, _>(3, |rng| Mint::new(rng.next_u64()), |a, b| a == b);
+    check_monoid::<Min<i32>, _, _>(4, |rng| rng.range(-100, 100) as i32, |a, b| a == b);
+    check_monoid::<Max<u64>, _, _>(5, |rng| rng.next_u64(), |a, b| a == b);
+    check_monoid::<Max<f64>, _, _>(6, |rng| rng.range(-100, 100) as f64, |a, b| a == b);
+    check_monoid::<Gcd<u64>, _, _>(7, |rng| rng.range(0, 12) as u64 * 6, |a, b| a == b);
+    check_monoid::<Xor<u32>, _, _>(8, |rng| rng.next_u64() as u32, |a, b| a == b);
+    check_monoid::<MinCount<i64>, _, _>(9, |rng| (rng.range(0, 3), 1), |a, b| a == b);
+}
+
+#[test]
+fn gcd_and_min_count_values() {
+    let seg_tree = SegmentTree::<Gcd<u64>>::with_nodes(&vec![12, 18, 30, 7]);
+    assert_eq!(seg_tree.query(..=2), 6);
+    assert_eq!(seg_tree.query(..), 1);
+    assert_eq!(seg_tree.query(1..=2), gcd(18, 30));
+
+    let seg_tree = SegmentTree::<MinCount<i64>>::with_func(5, |i| ([3, 1, 2, 1, 1][i], 1));
+    assert_eq!(seg_tree.query(..), (1, 3));
+    assert_eq!(seg_tree.query(2..=2), (2, 1));
+}
+
+#[test]
+fn max_subarray_sum_matches_brute_force() {
+    let mut rng = Rng::new(10);
+    for _ in 0..50 {
+        let n = rng.usize(1, 30);
+        let mut a = rng.vec(n, -10, 10);
+        let mut seg_tree =
+            SegmentTree::<MaxSubarraySum<i64>>::with_func(n, |i| SubarrayNode::new(a[i]));
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            if rng.bool() {
+                a[l] = rng.range(-10, 10);
+                seg_tree.set(l, SubarrayNode::new(a[l]));
+            } else {
+                let best = (l..=r)
+                    .flat_map(|i| (i..=r).map(move |j| (i, j)))
+                    .map(|(i, j)| a[i..=j].iter().sum::<i64>())
+                    .max()
+                    .unwrap();
+                let node = seg_tree.query(l..=r);
+                assert_eq!(node.best, best);
+                assert_eq!(node.sum, a[l..=r].iter().sum::<i64>());
+            }
+        }
+    }
+}
+
+#[test]
+fn lazy_operations_match_brute_force() {
+    check_lazy::<AddSum<i64>, _, _>(11, |rng| rng.range(-50, 50), |rng| rng.range(-5, 5));
+    check_lazy::<AssignSum<i64>, _, _>(
+        12,
+        |rng| rng.range(-50, 50),
+        |rng| rng.bool().then(|| rng.range(-5, 5)),
+    );
+    check_lazy::<AffineSum<i64>, _, _>(
+        13,
+        |rng| rng.range(-5, 5),
+        |rng| (rng.range(-2, 2), rng.range(-5, 5)),
+    );
+    check_lazy::<AffineSum<Mint>, _, _>(
+        14,
+        |rng| Mint::new(rng.next_u64()),
+        |rng| (Mint::new(rng.next_u64()), Mint::new(rng.next_u64())),
+    );
+    check_lazy::<AddSum<Mint>, _, _>(
+        15,
+        |rng| Mint::new(rng.next_u64()),
+        |rng| Mint::new(rng.next_u64()),
+    );
+    check_lazy::<AddMin<i64>, _, _>(16, |rng| rng.range(-50, 50), |rng| rng.range(-5, 5));
+    check_lazy::<AddMax<i64>, _, _>(17, |rng| rng.range(-50, 50), |rng| rng.range(-5, 5));
+    check_lazy::<AssignMin<i64>, _, _>(
+        18,
+        |rng| rng.range(-50, 50),
+        |rng| rng.bool().then(|| rng.range(-5, 5)),
+    );
+    check_lazy::<AssignMax<i32>, _, _>(
+        19,
+        |rng| rng.range(-50, 50) as i32,
+        |rng| rng.bool().then(|| rng.range(-5, 5) as i32),
+    );
+}
+
+#[test]
+fn sums_take_the_length_from_the_range() {
+    let mut recursive = LazySegmentTree::<AddSum<i64>>::with_defaults(5);
+    let mut iterative = IterativeLazySegmentTree::<AddSum<i64>>::with_defaults(5);
+    recursive.update(.., 2);
+    iterative.update(.., 2);
+    recursive.update(1..=3, 1);
+    iterative.update(1..=3, 1);
+    assert_eq!(recursive.query(..), 13);
+    assert_eq!(iterative.query(..), 13);
+    assert_eq!(recursive.query(2..), 8);
+    assert_eq!(iterative.query(2..), 8);
+
+    let mut affine = LazySegmentTree::<AffineSum<Mint>>::with_defaults(3);
+    affine.update(.., (Mint::one(), Mint::new(5u32)));
+    affine.update(..2, (Mint::new(2u32), Mint::one()));
+    assert_eq!(affine.query(..), Mint::new(27u32));
+    let mut assign = LazySegmentTree::<AssignSum<i64>>::with_nodes(vec![4, 7, 1]);
+    assign.update(1.., Some(-3));
+    assert_eq!(assign.query(..), -2);
+}
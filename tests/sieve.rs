@@ -0,0 +1,49 @@
+use competitive_programming::sieve::*;
+
+fn smallest_factor(n: u64) -> u64 {
+    (2..)
+        .find(|p| p * p > n || n % p == 0)
+        .map(|p| if p * p > n { n } else { p })
+        .unwrap()
+}
+
+#[test]
+fn matches_trial_division() {
+    const N: usize = 1000;
+    let sieve = Sieve::<N>::new();
+    for n in 2..=N {
+        assert_eq!(
+            sieve.is_prime(n),
+            smallest_factor(n as u64) == n as u64,
+            "{}",
+            n
+        );
+        assert_eq!(
+            sieve.smallest_prime_factor(n) as u64,
+            smallest_factor(n as u64)
+        );
+    }
+    assert!(!sieve.is_prime(0) && !sieve.is_prime(1));
+    assert!(!sieve.is_prime_big(0u64) && !sieve.is_prime_big(1u64));
+
+    for n in (2..(N * N) as u64).step_by(997) {
+        assert_eq!(sieve.is_prime_big(n), smallest_factor(n) == n, "{}", n);
+        assert_eq!(sieve.smallest_prime_factor_big(n), smallest_factor(n));
+    }
+    assert_eq!(sieve.primes().len(), 168);
+}
+
+#[test]
+fn is_prime_big_handles_small_numbers() {
+    let sieve = Sieve::<10>::new();
+    let primes: Vec<u64> = (0..30u64).filter(|&n| sieve.is_prime_big(n)).collect();
+    assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert!(!sieve.is_prime_big(100u64));
+    assert!(sieve.is_prime_big(97u64));
+}
+
+#[test]
+#[should_panic]
+fn too_big() {
+    Sieve::<10>::new().is_prime(11);
+}
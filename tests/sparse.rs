@@ -0,0 +1,97 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::math::gcd;
+use competitive_programming::seg_tree::SegmentTreeOperations;
+use competitive_programming::seg_tree_ops::Xor;
+use competitive_programming::sparse::*;
+
+#[test]
+fn sparse_table_matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 50);
+        let a = rng.vec(n, 1, 100);
+        let min = SparseTable::new(&a, |x, y| *x.min(y));
+        let gcd_table = SparseTable::new(&a, |x, y| gcd(*x, *y));
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            assert_eq!(min.query(l..=r), *a[l..=r].iter().min().unwrap());
+            assert_eq!(
+                gcd_table.query(l..r + 1),
+                a[l..=r].iter().fold(0, |g, &x| gcd(g, x))
+            );
+        }
+        assert_eq!(min.query(..), *a.iter().min().unwrap());
+    }
+}
+
+#[test]
+fn rmq_matches_brute_force() {
+    let mut rng = Rng::new(2);
+    for _ in 0..100 {
+        let n = rng.usize(1, 50);
+        let a = rng.vec(n, -10, 10);
+        let min = RMQ::<i64, false>::new(&a);
+        let max = RMQ::<i64, true, u32>::new(&a);
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            let i = min.query_index(l..=r);
+            assert!(l <= i && i <= r);
+            assert_eq!(a[i], *a[l..=r].iter().min().unwrap());
+            assert_eq!(min.query_value(l..=r), a[i]);
+
+            let i = max.query_index(l..=r);
+            assert!(l <= i && i <= r);
+            assert_eq!(a[i], *a[l..=r].iter().max().unwrap());
+        }
+    }
+}
+
+#[test]
+fn disjoint_sparse_table_matches_brute_force() {
+    let mut rng = Rng::new(3);
+    for _ in 0..100 {
+        let n = rng.usize(1, 70);
+        let a = rng.vec(n, -100, 100);
+        let sum = DisjointSparseTable::new(&a, |x, y| x + y);
+        let xor = DisjointSparseTable::new(&a, Xor::<i64>::merge);
+        // Non-commutative
+        let words: Vec<String> = a.iter().map(|x| x.to_string()).collect();
+        let concat = DisjointSparseTable::new(&words, |x, y| format!("{},{}", x, y));
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            assert_eq!(sum.query(l..=r), a[l..=r].iter().sum::<i64>());
+            assert_eq!(xor.query(l..r + 1), a[l..=r].iter().fold(0, |x, y| x ^ y));
+            assert_eq!(concat.query(l..=r), words[l..=r].join(","));
+        }
+        assert_eq!(sum.query(..), a.iter().sum::<i64>());
+    }
+}
+
+#[test]
+fn disjoint_sparse_table_matrix_products() {
+    const MOD: u64 = 1_000_000_007;
+    type Mat = [[u64; 2]; 2];
+    let mul = |x: &Mat, y: &Mat| {
+        let mut z = [[0; 2]; 2];
+        for i in 0..2 {
+            for j in 0..2 {
+                z[i][j] = (x[i][0] * y[0][j] + x[i][1] * y[1][j]) % MOD;
+            }
+        }
+        z
+    };
+
+    let mut rng = Rng::new(4);
+    let n = 100;
+    let a: Vec<Mat> = (0..n)
+        .map(|_| [[0; 2]; 2].map(|row: [u64; 2]| row.map(|_| rng.range(0, 9) as u64)))
+        .collect();
+    let table = DisjointSparseTable::new(&a, mul);
+    for _ in 0..500 {
+        let (l, r) = rng.segment(n);
+        let expected = a[l + 1..=r].iter().fold(a[l], |acc, x| mul(&acc, x));
+        assert_eq!(table.query(l..=r), expected);
+    }
+}
@@ -0,0 +1,175 @@
+mod common;
+
+use competitive_programming::stress::*;
+use competitive_programming::template::custom_io::{ScannerOperations, WholeInputScanner, Writer};
+use competitive_programming::template::solution_driver::{SolutionDriver, TestCaseMode};
+use std::collections::HashSet;
+
+fn find(parent: &mut Vec<usize>, x: usize) -> usize {
+    if parent[x] != x {
+        parent[x] = find(parent, parent[x]);
+    }
+    parent[x]
+}
+
+/// Whether the edges connect all the `n` nodes.
+fn connected(n: usize, edges: &[(usize, usize)]) -> bool {
+    let mut parent: Vec<usize> = (0..n).collect();
+    let mut components = n;
+    for &(u, v) in edges {
+        let (u, v) = (find(&mut parent, u), find(&mut parent, v));
+        if u != v {
+            parent[u] = v;
+            components -= 1;
+        }
+    }
+    components == 1
+}
+
+#[test]
+fn generators_are_valid() {
+    let mut rng = Random::new(1);
+    for _ in 0..300 {
+        let n = rng.usize(1, 12);
+
+        let x = rng.int(-5, 5);
+        assert!((-5..=5).contains(&x));
+        assert!(rng.array(n, 1, 3).iter().all(|x| (1..=3).contains(x)));
+
+        let mut p = rng.permutation(n);
+        p.sort();
+        assert_eq!(p, (0..n).collect::<Vec<_>>());
+
+        let tree = rng.tree(n);
+        assert_eq!(tree.len(), n - 1);
+        assert!(connected(n, &tree));
+
+        let m = rng.usize(n - 1, n * (n - 1) / 2);
+        let graph = rng.connected_graph(n, m);
+        assert_eq!(graph.len(), m);
+        assert!(connected(n, &graph));
+        let distinct: HashSet<(usize, usize)> =
+            graph.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+        assert_eq!(distinct.len(), m);
+        assert!(graph.iter().all(|&(u, v)| u != v && u < n && v < n));
+
+        let s = rng.string(n, "ab");
+        assert_eq!(s.len(), n);
+        assert!(s.chars().all(|c| c == 'a' || c == 'b'));
+
+        let clauses = rng.two_sat(n, 5);
+        assert!(clauses.iter().all(|&(a, _, b, _)| a < n && b < n));
+    }
+}
+
+fn generate_array(rng: &mut Random, size: usize) -> String {
+    let n = rng.usize(1, size);
+    let a: Vec<String> = rng.array(n, 1, 10).iter().map(|x| x.to_string()).collect();
+    format!("{}\n{}\n", n, a.join(" "))
+}
+
+fn max_brute(input: &str) -> String {
+    let a: Vec<i64> = input
+        .split_whitespace()
+        .skip(1)
+        .map(|x| x.parse().unwrap())
+        .collect();
+    a.iter().max().unwrap().to_string()
+}
+
+#[test]
+fn passes_correct_solution() {
+    // Different whitespace in the output is fine
+    let solution = |input: &str| format!("  {}\n\n", max_brute(input));
+    assert!(stress(1, 300, 10, generate_array, solution, max_brute).is_ok());
+}
+
+#[test]
+fn finds_smallest_failing_input() {
+    // Ignores the last element
+    let solution = |input: &str| {
+        let a: Vec<i64> = input
+            .split_whitespace()
+            .skip(1)
+            .map(|x| x.parse().unwrap())
+            .collect();
+        a[..a.len() - 1]
+            .iter()
+            .max()
+            .copied()
+            .unwrap_or(0)
+            .to_string()
+    };
+    let failure = stress(1, 1000, 10, generate_array, solution, max_brute).unwrap_err();
+    assert_eq!(failure.input.split_whitespace().next(), Some("1"));
+    assert_ne!(solution(&failure.input), max_brute(&failure.input));
+}
+
+#[test]
+fn catches_panics() {
+    let solution = |input: &str| {
+        if input.starts_with('3') {
+            panic!("n = 3");
+        }
+        max_brute(input)
+    };
+    let failure = stress(1, 1000, 10, generate_array, solution, max_brute).unwrap_err();
+    assert_eq!(failure.found, "Panicked: n = 3");
+    assert!(format!("{:?}", failure).contains("Panicked: n = 3"));
+}
+
+/// Sum of every test case, using the template's scanner and writer in memory.
+struct SumSolution {
+    sc: WholeInputScanner,
+    out: Writer<Vec<u8>>,
+}
+impl SolutionDriver for SumSolution {
+    type Input = WholeInputScanner;
+    type Output = Vec<u8>;
+    type Answer = i64;
+    const MODE: TestCaseMode = TestCaseMode::Multiple;
+
+    fn io(&mut self) -> (&mut Self::Input, &mut Writer<Self::Output>) {
+        (&mut self.sc, &mut self.out)
+    }
+
+    fn solve(&mut self) -> Self::Answer {
+        let n: usize = self.sc.input();
+        (0..n).map(|_| self.sc.input::<i64>()).sum()
+    }
+}
+
+#[test]
+fn runs_solution_driver_in_memory() {
+    let run = |input: &str| {
+        let mut sol = SumSolution {
+            sc: WholeInputScanner::from_bytes(input.as_bytes().to_vec()),
+            out: Writer::new(Vec::new()),
+        };
+        sol.run();
+        String::from_utf8(sol.out.take_output()).unwrap()
+    };
+    let brute = |input: &str| {
+        let mut tokens = input.split_whitespace().map(|x| x.parse::<i64>().unwrap());
+        let t = tokens.next().unwrap();
+        (0..t)
+            .map(|_| {
+                let n = tokens.next().unwrap();
+                (0..n)
+                    .map(|_| tokens.next().unwrap())
+                    .sum::<i64>()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let generate = |rng: &mut Random, size: usize| {
+        let t = rng.usize(1, 3);
+        let mut input = format!("{}\n", t);
+        for _ in 0..t {
+            input += &generate_array(rng, size);
+        }
+        input
+    };
+    assert!(stress(1, 300, 10, generate, run, brute).is_ok());
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::string_algos::*;
+
+#[test]
+fn lps_matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..300 {
+        let n = rng.usize(0, 30);
+        let s: Vec<u8> = (0..n).map(|_| b'a' + rng.usize(0, 2) as u8).collect();
+        let lps = lps(&s);
+        assert_eq!(lps.len(), n + 1);
+        for i in 1..=n {
+            let border = (0..i).rev().find(|&k| s[..k] == s[i - k..i]).unwrap();
+            assert_eq!(lps[i], border, "{:?} {}", String::from_utf8_lossy(&s), i);
+        }
+    }
+}
+
+#[test]
+fn pattern_matching() {
+    let text = "abacababacab";
+    let pattern = "abac";
+    let combined: Vec<char> = pattern.chars().chain(['#']).chain(text.chars()).collect();
+    let matches: Vec<usize> = lps(&combined)
+        .iter()
+        .enumerate()
+        .filter(|&(_, &l)| l == pattern.len())
+        .map(|(i, _)| i - 2 * pattern.len() - 1)
+        .collect();
+    assert_eq!(matches, [0, 6]);
+}
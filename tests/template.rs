@@ -0,0 +1,400 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::template::custom_io::{
+    InteractiveScanner, ScanError, ScannerOperations, StreamScanner, WholeInputScanner, Writer,
+};
+use competitive_programming::template::solution_driver::{Line, SolutionDriver, TestCaseMode};
+use std::io::Write;
+use std::path::Path;
+
+/// Runs `check` with each of the scanners reading `input` (anything that's `AsRef<[u8]>`).
+macro_rules! for_each_scanner {
+    ($input:expr, |$sc:ident| $check:block) => {{
+        let input: &[u8] = AsRef::<[u8]>::as_ref(&$input);
+        {
+            let mut $sc = StreamScanner::from_reader(input);
+            $check
+        }
+        {
+            let mut $sc = WholeInputScanner::from_bytes(input.to_vec());
+            $check
+        }
+        {
+            let mut $sc = InteractiveScanner::from_reader(input);
+            $check
+        }
+    }};
+}
+
+/// Sum of every test case, with the scanner and the writer chosen by the caller.
+struct SumSolution<S: ScannerOperations, W: Write> {
+    sc: S,
+    out: Writer<W>,
+}
+impl<S: ScannerOperations, W: Write> SolutionDriver for SumSolution<S, W> {
+    type Input = S;
+    type Output = W;
+    type Answer = i64;
+    const MODE: TestCaseMode = TestCaseMode::Multiple;
+
+    fn io(&mut self) -> (&mut Self::Input, &mut Writer<Self::Output>) {
+        (&mut self.sc, &mut self.out)
+    }
+
+    fn solve(&mut self) -> Self::Answer {
+        let n: usize = self.sc.input();
+        (0..n).map(|_| self.sc.input::<i64>()).sum()
+    }
+}
+
+fn run<S: ScannerOperations>(sc: S) -> String {
+    let mut sol = SumSolution {
+        sc,
+        out: Writer::new(Vec::new()),
+    };
+    sol.run();
+    String::from_utf8(sol.out.take_output()).unwrap()
+}
+
+/// Every `*.in` file in the directory, with its `*.out` file.
+fn samples(dir: &str) -> Vec<(Vec<u8>, String)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(dir);
+    let mut samples: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "in"))
+        .collect();
+    samples.sort();
+    samples
+        .into_iter()
+        .map(|path| {
+            let expected = std::fs::read_to_string(path.with_extension("out")).unwrap();
+            (std::fs::read(path).unwrap(), expected)
+        })
+        .collect()
+}
+
+#[test]
+fn passes_samples_with_every_scanner() {
+    let samples = samples("tests/samples/sum");
+    assert!(!samples.is_empty());
+    for (input, expected) in samples {
+        assert_eq!(run(StreamScanner::from_reader(&input[..])), expected);
+        assert_eq!(run(WholeInputScanner::from_reader(&input[..])), expected);
+        assert_eq!(run(InteractiveScanner::from_reader(&input[..])), expected);
+    }
+}
+
+#[test]
+fn scanners_read_same_tokens() {
+    let mut rng = Rng::new(1);
+    for _ in 0..200 {
+        let n = rng.usize(1, 30);
+        let a = rng.vec(n, -1_000_000_000_000, 1_000_000_000_000);
+        let mut input = Vec::new();
+        for x in &a {
+            let sep = [" ", "\n", "\r\n", "  \t"][rng.usize(0, 3)];
+            write!(input, "{}{}", x, sep).unwrap();
+        }
+
+        let mut stream = StreamScanner::from_reader(&input[..]);
+        let mut whole = WholeInputScanner::from_bytes(input.clone());
+        // A tiny buffer, so that the integers are split between the reads
+        let mut interactive =
+            InteractiveScanner::from_reader(std::io::BufReader::with_capacity(3, &input[..]));
+        for &x in &a {
+            assert_eq!(stream.input_int::<i64>(), x);
+            assert_eq!(whole.input::<i64>(), x);
+            assert_eq!(interactive.input_int::<i64>(), x);
+        }
+        stream.skip_whitespaces();
+        whole.skip_whitespaces();
+        interactive.skip_whitespaces();
+        assert!(stream.is_eof() && whole.is_eof() && interactive.is_eof());
+        assert_eq!(stream.offset(), input.len());
+        assert_eq!(whole.offset(), input.len());
+        assert_eq!(interactive.offset(), input.len());
+    }
+}
+
+#[test]
+fn input_int_reads_the_extremes_and_rejects_overflows() {
+    let input = format!("{} {} {} {} 0255", i64::MIN, i64::MAX, u64::MAX, i128::MIN);
+    for_each_scanner!(input, |sc| {
+        assert_eq!(sc.input_int::<i64>(), i64::MIN);
+        assert_eq!(sc.input_int::<i64>(), i64::MAX);
+        assert_eq!(sc.input_int::<u64>(), u64::MAX);
+        assert_eq!(sc.input_int::<i128>(), i128::MIN);
+        assert_eq!(sc.input_int::<u8>(), 255);
+    });
+    for input in [
+        "9223372036854775808",
+        "-9223372036854775809",
+        "10000000000000000000",
+    ] {
+        for_each_scanner!(input, |sc| {
+            let read =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sc.input_int::<i64>()));
+            let error = read.expect_err(input);
+            assert_eq!(
+                error.downcast_ref::<String>().unwrap(),
+                "Failed to parse input"
+            );
+        });
+    }
+}
+
+#[test]
+fn whitespaces_only_is_eof() {
+    for input in ["", " ", "\n\r\n\t  \n"] {
+        for_each_scanner!(input, |sc| {
+            sc.skip_whitespaces();
+            assert!(sc.is_eof());
+            assert_eq!(sc.offset(), input.len());
+            assert_eq!(sc.peek_byte(), None);
+        });
+    }
+}
+
+#[test]
+fn try_input_reports_the_token_and_its_offset() {
+    for_each_scanner!("12 ab\n  3x", |sc| {
+        assert_eq!(sc.try_input::<i32>(), Ok(12));
+        match sc.try_input::<i32>() {
+            Err(ScanError::Parse { token, offset, .. }) => {
+                assert_eq!((token.as_str(), offset), ("ab", 3))
+            }
+            other => panic!("Unexpected {:?}", other),
+        }
+        let error = sc.try_input::<u8>().unwrap_err();
+        assert!(matches!(&error, ScanError::Parse { token, offset: 8, .. } if token == "3x"));
+        assert!(error
+            .to_string()
+            .starts_with("Failed to parse input \"3x\" at byte 8: "));
+        assert_eq!(sc.try_input::<i32>(), Err(ScanError::Eof));
+        assert_eq!(sc.try_input::<String>(), Err(ScanError::Eof));
+    });
+}
+
+#[test]
+fn tokens_end_at_eof() {
+    for input in ["1 -2 3", "1 -2 3\n", "1\n-2\r\n3\r\n\n"] {
+        for_each_scanner!(input, |sc| {
+            assert_eq!(sc.tokens::<i64>().collect::<Vec<_>>(), [1, -2, 3]);
+            assert!(sc.is_eof());
+            assert_eq!(sc.tokens::<i64>().next(), None);
+        });
+    }
+    for_each_scanner!("2\nab cd\n", |sc| {
+        let n: usize = sc.input();
+        assert_eq!(n, 2);
+        assert_eq!(sc.tokens::<String>().collect::<Vec<_>>(), ["ab", "cd"]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse input \"x\" at byte 2")]
+fn tokens_panic_on_invalid_token() {
+    let mut sc = WholeInputScanner::from_bytes(b"1 x 3".to_vec());
+    sc.tokens::<i64>().for_each(drop);
+}
+
+#[test]
+fn lines_and_chars_keep_multibyte_characters() {
+    let input = "héllo wörld  \r\n\r\n  третья строка\r\nlast";
+    for_each_scanner!(input, |sc| {
+        assert_eq!(sc.input_line(), "héllo wörld");
+        // The empty line is skipped
+        assert_eq!(sc.input_line(), "третья строка");
+        assert_eq!(sc.input_line(), "last");
+        assert!(sc.is_eof());
+    });
+    for_each_scanner!("añb 日本\r\nx", |sc| {
+        assert_eq!(sc.input_chars(), ['a', 'ñ', 'b']);
+        assert_eq!(sc.input_chars(), ['日', '本']);
+        assert_eq!(sc.input_chars(), ['x']);
+    });
+}
+
+#[test]
+fn input_bytes_accepts_invalid_utf8() {
+    for_each_scanner!(b"\xff\xfe ab\r\n#.\n", |sc| {
+        assert_eq!(sc.input_bytes(), [0xff, 0xfe]);
+        assert_eq!(sc.input_bytes(), b"ab");
+        assert_eq!(sc.input_bytes(), b"#.");
+        sc.skip_whitespaces();
+        assert!(sc.is_eof());
+    });
+}
+
+/// Everything printed into `out` since the last call.
+fn printed(out: &mut Writer<Vec<u8>>) -> String {
+    String::from_utf8(out.take_output()).unwrap()
+}
+
+#[test]
+fn integers_are_printed_like_display() {
+    let mut out = Writer::new(Vec::new());
+    out.print(i128::MIN).print(' ').print(u128::MAX).print(' ');
+    out.print(0u8).print(' ').print(0i64).print(' ');
+    out.print(i8::MIN).print(' ').println(-7isize);
+    let expected = format!("{} {} 0 0 -128 -7\n", i128::MIN, u128::MAX);
+    assert_eq!(printed(&mut out), expected);
+
+    let mut rng = Rng::new(2);
+    for _ in 0..1000 {
+        let x = rng.next_u64();
+        out.print(x).print(' ').println(x as i64);
+        assert_eq!(printed(&mut out), format!("{} {}\n", x, x as i64));
+    }
+}
+
+#[test]
+fn prints_iterators_yes_no_and_cases() {
+    let mut out = Writer::new(Vec::new());
+    out.print_iter([3, -1, 2], " ");
+    assert_eq!(printed(&mut out), "3 -1 2\n");
+    out.print_iter("abc".chars(), ", ");
+    assert_eq!(printed(&mut out), "a, b, c\n");
+    out.print_iter(Vec::<u32>::new(), " ");
+    assert_eq!(printed(&mut out), "\n");
+
+    out.print_yes_no(true).print_yes_no(false);
+    assert_eq!(printed(&mut out), "Yes\nNo\n");
+    out.print_case(1).println(5).print_case(12).println("ok");
+    assert_eq!(printed(&mut out), "Case #1: 5\nCase #12: ok\n");
+}
+
+/// A solution in the mode `$mode`, with `solve` reading from `$sc` and returning `$answer`.
+macro_rules! solution {
+    ($name:ident, $mode:ident, $answer:ty, |$sc:ident, $out:ident| $solve:expr) => {
+        struct $name {
+            sc: WholeInputScanner,
+            out: Writer<Vec<u8>>,
+        }
+        impl SolutionDriver for $name {
+            type Input = WholeInputScanner;
+            type Output = Vec<u8>;
+            type Answer = $answer;
+            const MODE: TestCaseMode = TestCaseMode::$mode;
+
+            fn io(&mut self) -> (&mut Self::Input, &mut Writer<Self::Output>) {
+                (&mut self.sc, &mut self.out)
+            }
+
+            fn solve(&mut self) -> Self::Answer {
+                let ($sc, $out) = (&mut self.sc, &mut self.out);
+                $solve
+            }
+        }
+        impl $name {
+            /// Runs the solution on `input`, and returns its output.
+            fn output(input: &str) -> String {
+                let mut sol = Self {
+                    sc: WholeInputScanner::from_bytes(input.as_bytes().to_vec()),
+                    out: Writer::new(Vec::new()),
+                };
+                sol.run();
+                printed(&mut sol.out)
+            }
+        }
+    };
+}
+
+/// The sum of `n` numbers.
+fn sum(sc: &mut WholeInputScanner) -> i64 {
+    let n: usize = sc.input();
+    sc.input_vec::<i64>(n).into_iter().sum()
+}
+
+solution!(SingleSum, Single, i64, |sc, _out| sum(sc));
+solution!(MultipleSum, Multiple, i64, |sc, _out| sum(sc));
+solution!(CaseNumberedSum, CaseNumbered, i64, |sc, _out| sum(sc));
+solution!(UntilEofSum, UntilEof, i64, |sc, _out| sum(sc));
+
+#[test]
+fn driver_runs_every_mode() {
+    assert_eq!(SingleSum::output("3\n1 2 3\n"), "6\n");
+    assert_eq!(MultipleSum::output("2\n1 5\n2 -1 -1\n"), "5\n-2\n");
+    assert_eq!(MultipleSum::output("0\n"), "");
+    assert_eq!(
+        CaseNumberedSum::output("2\n1 5\n2 -1 -1\n"),
+        "Case #1: 5\nCase #2: -2\n"
+    );
+    assert_eq!(UntilEofSum::output("1 5\n2 -1 -1\n\n"), "5\n-2\n");
+    assert_eq!(UntilEofSum::output("1 5\n2 -1 -1"), "5\n-2\n");
+    assert_eq!(UntilEofSum::output(""), "");
+}
+
+solution!(IsEven, Multiple, bool, |sc, _out| sc.input::<i64>() % 2
+    == 0);
+solution!(Sorted, Single, Vec<i64>, |sc, _out| {
+    let mut a: Vec<i64> = sc.tokens().collect();
+    a.sort();
+    a
+});
+solution!(Ratio, Multiple, String, |sc, _out| {
+    let (p, q) = sc.input_tuple::<(i64, i64)>();
+    format!("{:.3}", p as f64 / q as f64)
+});
+struct Fraction(i64, i64);
+impl std::fmt::Display for Fraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
+solution!(Reduced, CaseNumbered, Line<Fraction>, |sc, _out| {
+    let (p, q) = sc.input_tuple::<(i64, i64)>();
+    let g = (1..=p.min(q))
+        .rev()
+        .find(|g| p % g == 0 && q % g == 0)
+        .unwrap();
+    Line(Fraction(p / g, q / g))
+});
+solution!(PrintsItself, CaseNumbered, (), |sc, out| {
+    let x: char = sc.input();
+    writeln!(out, "{}{}", x, x).unwrap();
+});
+
+#[test]
+fn driver_prints_every_answer_type() {
+    assert_eq!(IsEven::output("3\n4 7 0"), "Yes\nNo\nYes\n");
+    assert_eq!(Sorted::output("3 -1 2\n"), "-1 2 3\n");
+    assert_eq!(Sorted::output("\n"), "\n");
+    assert_eq!(Ratio::output("2\n1 3\n-1 8\n"), "0.333\n-0.125\n");
+    assert_eq!(PrintsItself::output("2\na b"), "Case #1: aa\nCase #2: bb\n");
+    assert_eq!(
+        Reduced::output("2\n4 6\n5 5\n"),
+        "Case #1: 2/3\nCase #2: 1/1\n"
+    );
+}
+
+#[test]
+fn reads_tuples_matrices_and_grids() {
+    let input = "3 -4 abc 2.5\n1 2 3\n4 5 6\n#.#\n..#\n";
+    for_each_scanner!(input, |sc| {
+        let (n, m, s, x) = sc.input_tuple::<(usize, i64, String, f64)>();
+        assert_eq!((n, m, s.as_str(), x), (3, -4, "abc", 2.5));
+        assert_eq!(sc.input_matrix::<u32>(2, 3), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(sc.input_grid_bytes(2, 3), [b"#.#", b"..#"]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Grid row of unexpected length!")]
+fn grid_rows_must_have_the_given_length() {
+    let mut sc = WholeInputScanner::from_bytes(b"#.#\n..\n".to_vec());
+    sc.input_grid_bytes(2, 3);
+}
+
+#[test]
+fn converts_one_based_indices() {
+    let input = "4\n2 1 4\n1 2\n3 2\n1 4 -7\n";
+    for_each_scanner!(input, |sc| {
+        assert_eq!(sc.input_idx(), 3);
+        assert_eq!(sc.input_vec_idx(3), [1, 0, 3]);
+        assert_eq!(sc.input_edges(2), [(0, 1), (2, 1)]);
+        assert_eq!(sc.input_weighted_edges::<i64>(1), [(0, 3, -7)]);
+    });
+}
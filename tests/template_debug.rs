@@ -0,0 +1,75 @@
+//! Separate from `template.rs`, as the debug mode is a global flag.
+
+use competitive_programming::template::custom_io::{ScannerOperations, WholeInputScanner, Writer};
+use competitive_programming::template::solution_driver::{SolutionDriver, TestCaseMode};
+use competitive_programming::template::DEBUG;
+use std::process::Command;
+use std::sync::atomic::Ordering;
+
+/// Set for the child process running `debug_run`.
+const CHILD_ENV: &str = "TEMPLATE_DEBUG_CHILD";
+
+/// The sum of `n` numbers, panicking on negative numbers (after reading the whole test case).
+struct Solution {
+    sc: WholeInputScanner,
+    out: Writer<Vec<u8>>,
+}
+impl SolutionDriver for Solution {
+    type Input = WholeInputScanner;
+    type Output = Vec<u8>;
+    type Answer = i64;
+    const MODE: TestCaseMode = TestCaseMode::Multiple;
+
+    fn io(&mut self) -> (&mut Self::Input, &mut Writer<Self::Output>) {
+        (&mut self.sc, &mut self.out)
+    }
+
+    fn solve(&mut self) -> Self::Answer {
+        let n: usize = self.sc.input();
+        let a: Vec<i64> = self.sc.input_vec(n);
+        assert!(a.iter().all(|&x| x >= 0), "negative input");
+        a.into_iter().sum()
+    }
+}
+
+/// Runs the solution in the debug mode, only when started by `panicking_case_is_reported`.
+#[test]
+fn debug_run() {
+    if std::env::var_os(CHILD_ENV).is_none() {
+        return;
+    }
+    DEBUG.store(true, Ordering::Relaxed);
+    let mut sol = Solution {
+        sc: WholeInputScanner::from_bytes(b"3\n1 5\n2 1 -1\n1 7\n".to_vec()),
+        out: Writer::new(Vec::new()),
+    };
+    sol.run();
+    println!(
+        "output: {:?}",
+        String::from_utf8(sol.out.take_output()).unwrap()
+    );
+}
+
+#[test]
+fn panicking_case_is_reported() {
+    let child = Command::new(std::env::current_exe().unwrap())
+        .args(["debug_run", "--exact", "--nocapture", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(child.stdout).unwrap();
+    let stderr = String::from_utf8(child.stderr).unwrap();
+    assert!(child.status.success(), "{}", stderr);
+
+    // The second test case panics, and the third one still runs
+    assert!(stdout.contains(r#"output: "5\n7\n""#), "{}", stdout);
+    let reports: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("Test case #"))
+        .collect();
+    assert_eq!(reports.len(), 3, "{}", stderr);
+    assert!(reports[0].starts_with("Test case #1 took "));
+    assert!(reports[1].starts_with("Test case #2 panicked after "));
+    assert!(reports[1].ends_with(": negative input"));
+    assert!(reports[2].starts_with("Test case #3 took "));
+}
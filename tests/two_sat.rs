@@ -0,0 +1,40 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::two_sat::*;
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..500 {
+        let n = rng.usize(1, 6);
+        let m = rng.usize(0, 12);
+        let clauses: Vec<(usize, bool, usize, bool)> = (0..m)
+            .map(|_| {
+                (
+                    rng.usize(0, n - 1),
+                    rng.bool(),
+                    rng.usize(0, n - 1),
+                    rng.bool(),
+                )
+            })
+            .collect();
+        let holds = |value: &dyn Fn(usize) -> bool| {
+            clauses
+                .iter()
+                .all(|&(a, a_neg, b, b_neg)| value(a) != a_neg || value(b) != b_neg)
+        };
+
+        let mut two_sat = TwoSat::new(n, m);
+        for &(a, a_neg, b, b_neg) in &clauses {
+            two_sat.add_clause(a, a_neg, b, b_neg);
+        }
+        two_sat.init();
+
+        let satisfiable = (0..1u32 << n).any(|mask| holds(&|x| mask >> x & 1 == 1));
+        assert_eq!(two_sat.satisfiable, Some(satisfiable));
+        if satisfiable {
+            assert!(holds(&|x| two_sat.true_value[x]));
+        }
+    }
+}
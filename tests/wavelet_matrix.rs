@@ -0,0 +1,122 @@
+mod common;
+
+use common::Rng;
+use competitive_programming::wavelet_matrix::*;
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let n = rng.usize(1, 50);
+        let max = [1, 7, 100, 1 << 40][rng.usize(0, 3)];
+        let a: Vec<u64> = (0..n).map(|_| rng.range(0, max) as u64).collect();
+        let matrix = WaveletMatrix::new(&a);
+        for (i, &x) in a.iter().enumerate() {
+            assert_eq!(matrix.get(i), x);
+        }
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            let mut sorted = a[l..=r].to_vec();
+            sorted.sort();
+            let k = rng.usize(0, r - l);
+            assert_eq!(matrix.kth_smallest(l..=r, k), sorted[k]);
+            assert_eq!(matrix.kth_largest(l..r + 1, k), sorted[r - l - k]);
+
+            let x = if rng.bool() {
+                a[rng.usize(0, n - 1)]
+            } else {
+                rng.range(0, max + 1) as u64
+            };
+            assert_eq!(
+                matrix.rank(l..=r, x),
+                sorted.iter().filter(|&&y| y == x).count()
+            );
+            assert_eq!(
+                matrix.prev_value(l..=r, x),
+                sorted.iter().rev().find(|&&y| y < x).copied()
+            );
+            assert_eq!(
+                matrix.next_value(l..=r, x),
+                sorted.iter().find(|&&y| y >= x).copied()
+            );
+
+            let (lo, hi) = (rng.range(0, max + 1) as u64, rng.range(0, max + 1) as u64);
+            assert_eq!(
+                matrix.range_freq(l..=r, lo, hi),
+                sorted.iter().filter(|&&y| lo <= y && y < hi).count()
+            );
+        }
+    }
+}
+
+#[test]
+fn full_u64_range() {
+    let a = [u64::MAX, 0, u64::MAX - 1, 1 << 63, u64::MAX];
+    let matrix = WaveletMatrix::new(&a);
+    assert_eq!(matrix.kth_smallest(.., 4), u64::MAX);
+    assert_eq!(matrix.kth_smallest(1..4, 1), 1 << 63);
+    assert_eq!(matrix.rank(.., u64::MAX), 2);
+    assert_eq!(matrix.range_freq(.., 1, u64::MAX), 2);
+    assert_eq!(matrix.prev_value(.., u64::MAX), Some(u64::MAX - 1));
+    assert_eq!(matrix.next_value(1..=1, 1), None);
+    assert_eq!(matrix.rank(2..2, 0), 0);
+}
+
+#[test]
+fn compressed_matches_brute_force() {
+    let mut rng = Rng::new(2);
+    for _ in 0..100 {
+        let n = rng.usize(1, 50);
+        let a = rng.vec(n, -1_000_000_000_000, 1_000_000_000_000);
+        let a: Vec<i64> = a.iter().map(|&x| x % 20).collect();
+        let matrix = CompressedWaveletMatrix::new(&a);
+        for _ in 0..100 {
+            let (l, r) = rng.segment(n);
+            let mut sorted = a[l..=r].to_vec();
+            sorted.sort();
+            let k = rng.usize(0, r - l);
+            assert_eq!(matrix.kth_smallest(l..=r, k), sorted[k]);
+            assert_eq!(matrix.kth_largest(l..=r, k), sorted[r - l - k]);
+            assert_eq!(matrix.get(l), a[l]);
+
+            let x = rng.range(-25, 25);
+            assert_eq!(
+                matrix.rank(l..=r, &x),
+                sorted.iter().filter(|&&y| y == x).count()
+            );
+            assert_eq!(
+                matrix.prev_value(l..=r, &x),
+                sorted.iter().rev().find(|&&y| y < x).copied()
+            );
+            assert_eq!(
+                matrix.next_value(l..=r, &x),
+                sorted.iter().find(|&&y| y >= x).copied()
+            );
+            let (lo, hi) = (rng.range(-25, 25), rng.range(-25, 25));
+            assert_eq!(
+                matrix.range_freq(l..=r, &lo, &hi),
+                sorted.iter().filter(|&&y| lo <= y && y < hi).count()
+            );
+        }
+    }
+
+    let words = ["b", "a", "c", "a"].map(String::from);
+    let matrix = CompressedWaveletMatrix::new(&words);
+    assert_eq!(matrix.kth_smallest(.., 1), "a");
+    assert_eq!(
+        matrix.next_value(..3, &"ab".to_string()),
+        Some("b".to_string())
+    );
+}
+
+#[test]
+fn empty_matrix() {
+    let matrix = WaveletMatrix::new(&[]);
+    assert_eq!(matrix.rank(.., 0), 0);
+    assert_eq!(matrix.range_freq(.., 0, u64::MAX), 0);
+    assert_eq!(matrix.prev_value(.., 5), None);
+    assert_eq!(matrix.next_value(.., 5), None);
+    let compressed = CompressedWaveletMatrix::<i64>::new(&[]);
+    assert_eq!(compressed.rank(.., &-1), 0);
+    assert_eq!(compressed.range_freq(.., &-5, &5), 0);
+}